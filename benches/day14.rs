@@ -0,0 +1,41 @@
+//! The day 14 spin cycle on bitboards against the grid it replaced, on the
+//! real input.
+//!
+//! Run with `cargo bench --bench day14`. Solving took about 900ms on the grid
+//! and about 17ms on bitboards on one machine, with a single spin cycle at
+//! about 90µs.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc_2023::{days::day14b, input};
+
+const CYCLES: usize = 1_000_000_000;
+
+fn spin(c: &mut Criterion) {
+    let input = match input::read(14, None) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("skipping day 14 benchmark: {}", err);
+            return;
+        }
+    };
+    let (layout, rocks) = day14b::parse_bitboards(&input).expect("invalid day 14 input");
+
+    let mut group = c.benchmark_group("day14");
+    group.sample_size(10);
+    group.bench_function("bitboard tilt cycle", |b| {
+        b.iter(|| layout.tilt_cycle(black_box(&rocks)))
+    });
+    group.bench_function("bitboard solve", |b| {
+        b.iter(|| day14b::solve(black_box(&input), CYCLES))
+    });
+    group.bench_function("grid solve", |b| {
+        b.iter(|| day14b::solve_grid(black_box(&input), CYCLES))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, spin);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+//! The day 20 pulse simulator against the one it replaced, a button press at
+//! a time on the real input.
+//!
+//! Run with `cargo bench --bench day20`; criterion reports presses per second
+//! as elements per second. Interning module names, keeping conjunction inputs
+//! in a bitset and queueing pulses in a `VecDeque` took a press from about
+//! 13µs to about 0.95µs on one machine, or about 75,000 to 1,060,000 presses
+//! per second.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use aoc_2023::{
+    days::day20a::{Machine, NamedMachine},
+    input,
+};
+
+fn press(c: &mut Criterion) {
+    let input = match input::read(20, None) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("skipping day 20 benchmark: {}", err);
+            return;
+        }
+    };
+    let mut machine: Machine = input.parse().expect("invalid day 20 input");
+    let mut named = NamedMachine::from(&machine);
+
+    let mut group = c.benchmark_group("day20");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("interned press", |b| {
+        b.iter(|| machine.press(|passed| _ = black_box(passed)))
+    });
+    group.bench_function("named press", |b| {
+        b.iter(|| named.press(|passed| _ = black_box(passed)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, press);
+criterion_main!(benches);
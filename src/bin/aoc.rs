@@ -0,0 +1,123 @@
+use std::{collections::HashMap, env, io, panic, process, time::Instant};
+
+use aoc_2023::{
+    input,
+    registry::{self, Solution, SOLUTIONS},
+};
+
+const USAGE: &str = "usage: aoc run <day|all> [a|b] [--input <path|->]";
+
+enum Outcome {
+    Answer(String),
+    Failed(String),
+    /// No input was given and none was found in the usual places.
+    Skipped,
+}
+
+fn select(args: &[String]) -> Option<Vec<&'static Solution>> {
+    match args {
+        [cmd, which] if cmd == "run" && which == "all" => Some(SOLUTIONS.iter().collect()),
+        [cmd, day] if cmd == "run" => Some(registry::for_day(day.parse().ok()?).collect()),
+        [cmd, day, part] if cmd == "run" => {
+            let mut part = part.chars();
+            let (Some(part), None) = (part.next(), part.next()) else {
+                return None;
+            };
+            Some(
+                registry::find(day.parse().ok()?, part)
+                    .into_iter()
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Removes `--input <path>` from the arguments, returning the path if given.
+fn take_input_arg(args: &mut Vec<String>) -> Option<Option<String>> {
+    let Some(index) = args.iter().position(|arg| arg == "--input") else {
+        return Some(None);
+    };
+    if index + 1 >= args.len() {
+        return None;
+    }
+    let path = args.remove(index + 1);
+    args.remove(index);
+    Some(Some(path))
+}
+
+fn run(solution: &Solution, input: &io::Result<String>, explicit: bool) -> Outcome {
+    let input = match input {
+        Ok(input) => input,
+        Err(err) if err.kind() == io::ErrorKind::NotFound && !explicit => return Outcome::Skipped,
+        Err(err) => return Outcome::Failed(err.to_string()),
+    };
+    match panic::catch_unwind(|| (solution.solve)(input)) {
+        Ok(Ok(ans)) => Outcome::Answer(ans),
+        Ok(Err(err)) => Outcome::Failed(err.to_string()),
+        Err(_) => Outcome::Failed("panicked".to_string()),
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let input_arg = take_input_arg(&mut args).unwrap_or_else(|| {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    });
+    let solutions = match select(&args) {
+        Some(solutions) if !solutions.is_empty() => solutions,
+        Some(_) => {
+            eprintln!("no solution registered for {}", args[1..].join(" "));
+            process::exit(2);
+        }
+        None => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    let days: Vec<u8> = solutions.iter().map(|s| s.day).collect();
+    if input_arg.is_some() && days.iter().any(|&day| day != days[0]) {
+        eprintln!("--input can only be used when running a single day");
+        process::exit(2);
+    }
+
+    let mut inputs: HashMap<u8, io::Result<String>> = HashMap::new();
+    let mut results = Vec::new();
+    for solution in solutions {
+        let input = inputs
+            .entry(solution.day)
+            .or_insert_with(|| input::read(solution.day, input_arg.as_deref()));
+        let start = Instant::now();
+        let outcome = run(solution, input, input_arg.is_some());
+        results.push((solution, outcome, start.elapsed()));
+    }
+
+    println!(
+        "{:>3}  {:<4}  {:<20}  {:>10}",
+        "Day", "Part", "Answer", "Time"
+    );
+    let mut failed = 0;
+    for (solution, outcome, elapsed) in results.iter() {
+        let ans = match outcome {
+            Outcome::Answer(ans) => ans.clone(),
+            Outcome::Failed(reason) => {
+                failed += 1;
+                format!("FAILED ({})", reason)
+            }
+            Outcome::Skipped => "skipped (no input)".to_string(),
+        };
+        println!(
+            "{:>3}  {:<4}  {:<20}  {:>10}",
+            solution.day,
+            solution.part,
+            ans,
+            format!("{:.2?}", elapsed)
+        );
+    }
+    if failed > 0 {
+        eprintln!("{} of {} parts failed", failed, results.len());
+        process::exit(1);
+    }
+}
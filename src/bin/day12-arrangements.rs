@@ -0,0 +1,52 @@
+use std::{env, process};
+
+use aoc_2023::{days::day12a::Row, parse};
+
+const USAGE: &str =
+    "usage: day12-arrangements '<row>' [--unfold <n>] [--limit <n>] [--sample <n>] [--seed <n>]";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+/// Lists the arrangements of one row of springs, or a uniform random sample
+/// of them, to check the counts against.
+fn main() {
+    let mut args = env::args().skip(1);
+    let Some(line) = args.next() else { usage() };
+    let (mut unfold, mut limit, mut sample, mut seed) = (1, None, None, None);
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_else(|| usage());
+        match flag.as_str() {
+            "--unfold" => unfold = value as usize,
+            "--limit" => limit = Some(value as usize),
+            "--sample" => sample = Some(value as usize),
+            "--seed" => seed = Some(value),
+            _ => usage(),
+        }
+    }
+
+    let row: Row = parse::or_exit(12, &line, line.parse());
+    let row = row.unfold(unfold);
+    let sampler = row.sampler();
+    let shown = match sample {
+        Some(samples) => {
+            let mut rng = seed.map_or_else(fastrand::Rng::new, fastrand::Rng::with_seed);
+            let samples = (0..samples).map_while(|_| sampler.sample(&mut rng));
+            samples
+                .take(limit.unwrap_or(usize::MAX))
+                .inspect(|a| println!("{}", a))
+                .count()
+        }
+        None => row
+            .arrangements()
+            .take(limit.unwrap_or(usize::MAX))
+            .inspect(|a| println!("{}", a))
+            .count(),
+    };
+    eprintln!("{} shown of {} arrangements", shown, sampler.count());
+}
@@ -0,0 +1,12 @@
+use std::env;
+
+use aoc_2023::{days::day17a, input, parse};
+
+fn main() {
+    let input = input::load(17);
+    let route = parse::or_exit(17, &input, day17a::route(&input));
+    if env::var_os(input::TRACE_VAR).is_some() {
+        println!("{}", route.render());
+    }
+    println!("{}", route.heat_loss());
+}
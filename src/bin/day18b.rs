@@ -0,0 +1,7 @@
+use aoc_2023::{days::day18b::solve, input, parse};
+
+fn main() {
+    let input = input::load(18);
+    let ans = parse::or_exit(18, &input, solve(&input));
+    println!("{}", ans);
+}
@@ -0,0 +1,14 @@
+use std::env;
+
+use aoc_2023::{days::day20b, input, parse};
+
+fn main() {
+    let input = input::load(20);
+    if env::var_os(input::TRACE_VAR).is_some() {
+        for period in parse::or_exit(20, &input, day20b::periods(&input)) {
+            println!("{}", period);
+        }
+    }
+    let ans = parse::or_exit(20, &input, day20b::solve(&input));
+    println!("{}", ans);
+}
@@ -0,0 +1,7 @@
+use aoc_2023::{days::day21b::solve, input, parse};
+
+fn main() {
+    let input = input::load(21);
+    let ans = parse::or_exit(21, &input, solve(&input, 26501365));
+    println!("{}", ans);
+}
@@ -0,0 +1,7 @@
+use aoc_2023::{days::day23a::solve, input, parse};
+
+fn main() {
+    let input = input::load(23);
+    let ans = parse::or_exit(23, &input, solve(&input));
+    println!("{}", ans);
+}
@@ -0,0 +1,7 @@
+use aoc_2023::{days::day24a::solve, input, parse};
+
+fn main() {
+    let input = input::load(24);
+    let ans = parse::or_exit(24, &input, solve(&input, 200000000000000, 400000000000000));
+    println!("{}", ans);
+}
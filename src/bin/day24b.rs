@@ -0,0 +1,12 @@
+use std::env;
+
+use aoc_2023::{days::day24b, input, parse};
+
+fn main() {
+    let input = input::load(24);
+    if env::var_os(input::TRACE_VAR).is_some() {
+        println!("{}", parse::or_exit(24, &input, day24b::explain(&input)));
+    }
+    let ans = parse::or_exit(24, &input, day24b::solve(&input));
+    println!("{}", ans);
+}
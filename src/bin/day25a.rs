@@ -0,0 +1,12 @@
+use std::env;
+
+use aoc_2023::{days::day25a, input, parse};
+
+fn main() {
+    let input = input::load(25);
+    if env::var_os(input::TRACE_VAR).is_some() {
+        println!("{}", parse::or_exit(25, &input, day25a::explain(&input)));
+    }
+    let ans = parse::or_exit(25, &input, day25a::solve(&input));
+    println!("{}", ans);
+}
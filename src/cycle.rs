@@ -0,0 +1,147 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+/// Where a sequence of states `x0, x1 = step(x0), ...` starts repeating:
+/// `start` is the first step whose state comes round again, `len` steps
+/// later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+
+    /// The state after `n` steps from `start`, taking no more than
+    /// `self.start + self.len` steps to get there.
+    pub fn state_at<S: Clone>(&self, start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        let mut state = start.clone();
+        for _ in 0..self.equivalent_step(n) {
+            state = step(&state);
+        }
+        state
+    }
+}
+
+/// The states seen while looking for a cycle, up to the point it closed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History<S> {
+    pub cycle: Cycle,
+    /// The states at steps `0..cycle.start + cycle.len`.
+    states: Vec<S>,
+}
+
+impl<S> History<S> {
+    /// The state after `n` steps, for any `n`.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.equivalent_step(n)]
+    }
+}
+
+/// Finds the cycle by remembering every state until one repeats. Takes
+/// exactly `start + len` steps, and keeps each state so any later one can
+/// be looked up.
+pub fn find_cycle<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S) -> History<S> {
+    find_cycle_by_key(start, step, S::clone)
+}
+
+/// Like `find_cycle`, but recognises states by `key`, which can be much
+/// cheaper to store and compare than the states themselves. Two states with
+/// the same key are taken to be the same, so a hash used as a key must be
+/// wide enough to make collisions unlikely.
+pub fn find_cycle_by_key<S, K: Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> History<S> {
+    let mut seen: FxHashMap<K, usize> = FxHashMap::default();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        let state_key = key(&state);
+        if let Some(&first) = seen.get(&state_key) {
+            let cycle = Cycle {
+                start: first,
+                len: states.len() - first,
+            };
+            return History { cycle, states };
+        }
+        seen.insert(state_key, states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which holds only two states at a
+/// time but takes a few times as many steps as `find_cycle`.
+pub fn brent<S: Clone + Eq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length by letting the hare run ahead in powers of two, with
+    // the tortoise waiting at the start of each run.
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+    }
+
+    // With the hare `len` steps ahead, the two meet where the cycle starts.
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+    let mut first = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        first += 1;
+    }
+    Cycle { start: first, len }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        let step = |&x: &u64| (x * x + 1) % 255;
+        let history = find_cycle(3, step);
+        let cycle = brent(&3, step);
+        assert_eq!(history.cycle, cycle);
+        assert_eq!(cycle, Cycle { start: 2, len: 6 });
+
+        let mut states = vec![3];
+        for _ in 0..100 {
+            states.push(step(states.last().unwrap()));
+        }
+        for (n, state) in states.iter().enumerate() {
+            assert_eq!(history.state_at(n), state);
+            assert_eq!(cycle.state_at(&3, step, n), *state);
+        }
+        assert_eq!(cycle.equivalent_step(1_000_000_001), 5);
+
+        let keyed = find_cycle_by_key(3, step, |&x| x % 256);
+        assert_eq!(keyed, history);
+
+        let still = find_cycle('x', |&c| c);
+        assert_eq!(still.cycle, Cycle { start: 0, len: 1 });
+        assert_eq!(brent(&'x', |&c| c), still.cycle);
+    }
+}
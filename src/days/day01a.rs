@@ -0,0 +1,19 @@
+use crate::parse::ParseError;
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    input
+        .split("\n")
+        .map(|line| {
+            if line.is_empty() {
+                return Ok(0);
+            }
+            let mut chars = line.chars().filter(|c| c.is_ascii_digit());
+            let first = chars.clone().next();
+            let first = first.ok_or_else(|| ParseError::new(line, "no digit in"))?;
+            let last = chars.next_back().unwrap_or(first);
+            Ok((first.to_string() + &last.to_string())
+                .parse::<u32>()
+                .unwrap())
+        })
+        .sum()
+}
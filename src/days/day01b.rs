@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::parse::ParseError;
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let digits: HashMap<&str, i32> = [
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+    ]
+    .iter()
+    .cloned()
+    .collect();
+
+    input
+        .split("\n")
+        .map(|line| {
+            if line.is_empty() {
+                return Ok(0);
+            }
+            let mut first = None;
+            let mut first_index = None;
+            let mut last = None;
+            let mut last_index = None;
+
+            for (k, v) in digits.iter() {
+                let indices = line.match_indices(k);
+                for (i, _) in indices {
+                    if first_index.is_none() || i < first_index.unwrap() {
+                        first_index = Some(i);
+                        first = Some(v.to_string());
+                    }
+                    if last_index.is_none() || i > last_index.unwrap() {
+                        last_index = Some(i);
+                        last = Some(v.to_string());
+                    }
+                }
+            }
+
+            match (first, last) {
+                (Some(first), Some(last)) => Ok((first + &last).parse::<u32>().unwrap()),
+                _ => Err(ParseError::new(line, "no digit in")),
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,37 @@
+use crate::parse::{self, ParseError};
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    input
+        .split("\n")
+        .map(|line| {
+            if line.is_empty() {
+                return Ok(0);
+            }
+            let (game, hands) = parse::split_once(line, ":")?;
+            let game = parse::number::<u32>(game.split(" ").last().unwrap())?;
+            let hands = hands.trim().split(";").collect::<Vec<_>>();
+            for hand in hands {
+                let items = hand.split(",").collect::<Vec<_>>();
+                for item in items {
+                    let item = item.trim();
+                    let (num, color) = parse::split_once(item, " ")?;
+                    let num = parse::number::<u32>(num)?;
+                    match color {
+                        "red" if num > 12 => {
+                            return Ok(0);
+                        }
+                        "green" if num > 13 => {
+                            return Ok(0);
+                        }
+                        "blue" if num > 14 => {
+                            return Ok(0);
+                        }
+                        "red" | "green" | "blue" => (),
+                        _ => return Err(ParseError::new(color, "unknown color")),
+                    }
+                }
+            }
+            Ok(game)
+        })
+        .sum()
+}
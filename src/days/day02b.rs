@@ -0,0 +1,40 @@
+use crate::parse::{self, ParseError};
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    input
+        .split("\n")
+        .map(|line| {
+            if line.is_empty() {
+                return Ok(0);
+            }
+            let (game, hands) = parse::split_once(line, ":")?;
+            let _game = parse::number::<u32>(game.split(" ").last().unwrap())?;
+            let hands = hands.trim().split(";").collect::<Vec<_>>();
+            let mut min_red = 0;
+            let mut min_green = 0;
+            let mut min_blue = 0;
+            for hand in hands {
+                let items = hand.split(",").collect::<Vec<_>>();
+                for item in items {
+                    let item = item.trim();
+                    let (num, color) = parse::split_once(item, " ")?;
+                    let num = parse::number::<u32>(num)?;
+                    match color {
+                        "red" if num > min_red => {
+                            min_red = num;
+                        }
+                        "green" if num > min_green => {
+                            min_green = num;
+                        }
+                        "blue" if num > min_blue => {
+                            min_blue = num;
+                        }
+                        "red" | "green" | "blue" => (),
+                        _ => return Err(ParseError::new(color, "unknown color")),
+                    }
+                }
+            }
+            Ok(min_red * min_green * min_blue)
+        })
+        .sum()
+}
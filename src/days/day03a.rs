@@ -0,0 +1,42 @@
+use crate::{grid::Grid, parse::ParseError};
+
+pub fn solve(input: &str) -> Result<i32, ParseError> {
+    let grid = Grid::try_parse(input, Ok)?;
+    let mut ans = 0;
+    for (y, line) in grid.rows().enumerate() {
+        let mut num: Option<String> = None;
+        let mut is_part = false;
+        for (x, c) in line.iter().enumerate() {
+            if c.is_ascii_digit() {
+                if !is_part {
+                    for adjacent in grid.neighbors8((x, y)) {
+                        let k = grid[adjacent];
+                        if !k.is_ascii_digit() && k != '.' {
+                            is_part = true;
+                            break;
+                        }
+                    }
+                }
+                if num.is_none() {
+                    num = Some(c.to_string());
+                } else {
+                    num = Some(num.unwrap() + &c.to_string());
+                }
+            } else {
+                if let Some(num) = num {
+                    if is_part {
+                        ans += num.parse::<i32>().unwrap();
+                    }
+                }
+                num = None;
+                is_part = false;
+            }
+        }
+        if let Some(num) = num {
+            if is_part {
+                ans += num.parse::<i32>().unwrap();
+            }
+        }
+    }
+    Ok(ans)
+}
@@ -0,0 +1,52 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{grid::Grid, parse::ParseError};
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let grid = Grid::try_parse(input, Ok)?;
+    let mut gears: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+    for (y, line) in grid.rows().enumerate() {
+        let mut num: Option<String> = None;
+        let mut adjacent_gears = HashSet::new();
+        for (x, c) in line.iter().enumerate() {
+            if c.is_ascii_digit() {
+                for adjacent in grid.neighbors8((x, y)) {
+                    if grid[adjacent] == '*' {
+                        adjacent_gears.insert(adjacent);
+                    }
+                }
+                if num.is_none() {
+                    num = Some(c.to_string());
+                } else {
+                    num = Some(num.unwrap() + &c.to_string());
+                }
+            } else {
+                if let Some(num) = num {
+                    for gear in adjacent_gears.iter() {
+                        gears
+                            .entry(*gear)
+                            .or_default()
+                            .push(num.parse::<u32>().unwrap());
+                    }
+                }
+                num = None;
+                adjacent_gears = HashSet::new();
+            }
+        }
+        if let Some(num) = num {
+            for gear in adjacent_gears.iter() {
+                gears
+                    .entry(*gear)
+                    .or_default()
+                    .push(num.parse::<u32>().unwrap());
+            }
+        }
+    }
+
+    let ans = gears
+        .iter()
+        .filter(|(_, v)| v.len() == 2)
+        .map(|(_, v)| v.iter().copied().reduce(|a, b| a * b).unwrap())
+        .sum::<u32>();
+    Ok(ans)
+}
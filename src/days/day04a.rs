@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+
+use crate::parse::{self, ParseError};
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    input
+        .split("\n")
+        .map(|line| {
+            if line.is_empty() {
+                return Ok(0);
+            }
+            let (card, mine) = parse::split_once(line.trim(), " | ")?;
+            let (_, winning) = parse::split_once(card, ": ")?;
+            let winning = winning.split_whitespace().collect::<HashSet<&str>>();
+            let mine = mine.split_whitespace().collect::<HashSet<&str>>();
+            let matching = winning.intersection(&mine).collect::<HashSet<&&str>>();
+            let points = {
+                if matching.is_empty() {
+                    0
+                } else {
+                    2u32.pow((matching.len() - 1) as u32)
+                }
+            };
+            Ok(points)
+        })
+        .sum()
+}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::parse::{self, ParseError};
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let matching: HashMap<u32, u32> = input
+        .split("\n")
+        .map(|line| {
+            if line.is_empty() {
+                return Ok((0, 0));
+            }
+            let (card, mine) = parse::split_once(line.trim(), " | ")?;
+            let (card, winning) = parse::split_once(card, ": ")?;
+            let card = card.split_whitespace().nth(1);
+            let card = parse::number::<u32>(
+                card.ok_or_else(|| ParseError::missing(line, "card number"))?,
+            )?;
+            let winning = winning.split_whitespace().collect::<HashSet<&str>>();
+            let mine = mine.split_whitespace().collect::<HashSet<&str>>();
+            let matching = winning.intersection(&mine).collect::<HashSet<&&str>>();
+            Ok((card, matching.len() as u32))
+        })
+        .collect::<Result<_, _>>()?;
+    let mut count: HashMap<u32, u32> = (1..matching.len() as u32).map(|x| (x, 1)).collect();
+    for card in 1..matching.len() as u32 {
+        let my_matching = matching[&card];
+        let my_count = count[&card];
+        for i in 1..=my_matching {
+            let new_card = card + i;
+            let new_count = count[&new_card] + my_count;
+            count.insert(new_card, new_count);
+        }
+        /*println!("Card {}: matching={} count={}", card, my_matching, my_count);
+        for card in 1..matching.len() as u32 {
+            println!("  Card {}: count={}", card, count[&card]);
+        }*/
+    }
+    let ans: u32 = count.values().sum();
+    Ok(ans)
+}
@@ -0,0 +1,136 @@
+use crate::{
+    interval::{IntervalMap, Range},
+    parse::{self, ParseError},
+};
+
+/// The seed numbers and the maps taking each category to the next, from
+/// seed to location.
+pub(crate) struct Almanac {
+    pub seeds: Vec<u64>,
+    /// The category names in order, one more than there are layers.
+    pub categories: Vec<String>,
+    pub layers: Vec<IntervalMap>,
+    /// The `(src, dst)` lines each layer was read from, in input order.
+    pub mappings: Vec<Vec<(Range, u64)>>,
+}
+
+impl Almanac {
+    /// All the layers collapsed into one map from seed to location.
+    pub fn location_map(&self) -> IntervalMap {
+        self.layers
+            .iter()
+            .fold(IntervalMap::identity(), |map, layer| map.compose(layer))
+    }
+}
+
+/// Reads one `dst src len` line of a map.
+fn parse_mapping(line: &str) -> Result<(Range, u64), ParseError> {
+    let mut nums = line.split_whitespace();
+    let mut next = |what| parse::number(parse::next(&mut nums, line, what)?);
+    let (dst, src, len) = (next("destination")?, next("source")?, next("length")?);
+    Ok((Range::new(src, len), dst))
+}
+
+/// The two category names of a map section, the map between them and the
+/// lines it was built from.
+type Layer<'a> = (&'a str, &'a str, IntervalMap, Vec<(Range, u64)>);
+
+/// Reads a `src-to-dst map:` section.
+fn parse_layer(section: &str) -> Result<Layer<'_>, ParseError> {
+    let mut lines = section.lines().map(str::trim);
+    let header = lines.next().unwrap_or_default();
+    let name = header
+        .strip_suffix(" map:")
+        .ok_or_else(|| ParseError::new(header, "expected ' map:' after"))?;
+    let (src, dst) = parse::split_once(name, "-to-")?;
+    let mappings: Vec<_> = lines.map(parse_mapping).collect::<Result<_, _>>()?;
+    let map = IntervalMap::new(mappings.iter().copied())
+        .ok_or_else(|| ParseError::new(section, "overlapping map ranges"))?;
+    Ok((src, dst, map, mappings))
+}
+
+pub(crate) fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+    let mut sections = input.trim().split("\n\n");
+    let seeds = sections.next().unwrap_or_default();
+    let seeds = parse::split_once(seeds, ": ")?
+        .1
+        .split_whitespace()
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+    let mut categories: Vec<String> = Vec::new();
+    let (mut layers, mut mappings) = (Vec::new(), Vec::new());
+    for section in sections {
+        let (src, dst, map, lines) = parse_layer(section)?;
+        match categories.last() {
+            None => categories.push(src.to_string()),
+            Some(last) if last != src => {
+                return Err(ParseError::new(src, "map does not follow the previous one"));
+            }
+            Some(_) => {}
+        }
+        categories.push(dst.to_string());
+        layers.push(map);
+        mappings.push(lines);
+    }
+    Ok(Almanac {
+        seeds,
+        categories,
+        layers,
+        mappings,
+    })
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let almanac = parse_input(input)?;
+    let map = almanac.location_map();
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| map.get(seed))
+        .min()
+        .ok_or_else(|| ParseError::missing(input, "seeds"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4";
+        assert_eq!(solve(input), Ok(35));
+    }
+}
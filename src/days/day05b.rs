@@ -0,0 +1,148 @@
+use super::day05a::parse_input;
+use crate::{interval::Range, parse::ParseError};
+
+fn seed_ranges(input: &str, seeds: &[u64]) -> Result<Vec<Range>, ParseError> {
+    seeds
+        .chunks(2)
+        .map(|chunk| match *chunk {
+            [start, len] => Ok(Range::new(start, len)),
+            _ => Err(ParseError::missing(input, "seed range length")),
+        })
+        .collect()
+}
+
+/// Follows the lowest location back through the maps to the seed it came
+/// from, listing its number in every category from seed to location along
+/// with the map line that took it there, if any.
+pub fn trace(input: &str) -> Result<String, ParseError> {
+    let almanac = parse_input(input)?;
+    let seeds = seed_ranges(input, &almanac.seeds)?;
+    // The values each category can take, given the seeds.
+    let mut reachable = vec![seeds.clone()];
+    for layer in &almanac.layers {
+        let next = layer.image(reachable.last().unwrap());
+        reachable.push(next);
+    }
+    let mut value = reachable
+        .last()
+        .unwrap()
+        .first()
+        .map(|range| range.start)
+        .ok_or_else(|| ParseError::missing(input, "seeds"))?;
+
+    let mut values = vec![value];
+    for (layer, before) in almanac.layers.iter().zip(&reachable).rev() {
+        // A map needn't be one-to-one, so take the lowest value that both
+        // reaches this one and comes from a seed.
+        let sources = layer.preimage(&[Range::new(value, 1)]);
+        value = sources
+            .iter()
+            .flat_map(|source| before.iter().filter_map(|range| range.intersection(source)))
+            .map(|range| range.start)
+            .min()
+            .unwrap();
+        values.push(value);
+    }
+    values.reverse();
+
+    let seed = values[0];
+    let range = seeds
+        .iter()
+        .find(|range| range.start <= seed && seed < range.end())
+        .unwrap();
+    let mut lines = vec![format!(
+        "{} {} (from seed range {}..{})",
+        almanac.categories[0],
+        seed,
+        range.start,
+        range.end()
+    )];
+    let steps = values.windows(2).zip(&almanac.mappings);
+    for (category, (step, mappings)) in almanac.categories[1..].iter().zip(steps) {
+        let (from, to) = (step[0], step[1]);
+        let mapping = mappings
+            .iter()
+            .find(|(src, _)| src.start <= from && from < src.end());
+        lines.push(match mapping {
+            Some((src, dst)) => format!(
+                "{} {} (mapped {}..{} -> {})",
+                category,
+                to,
+                src.start,
+                src.end(),
+                dst
+            ),
+            None => format!("{} {} (unmapped)", category, to),
+        });
+    }
+    Ok(lines.join("\n"))
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let almanac = parse_input(input)?;
+    let seeds = seed_ranges(input, &almanac.seeds)?;
+    let locations = almanac.location_map().image(&seeds);
+    locations
+        .first()
+        .map(|range| range.start)
+        .ok_or_else(|| ParseError::missing(input, "seeds"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4";
+        let trace = trace(input).unwrap();
+        let lines: Vec<_> = trace.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "seed 82 (from seed range 79..93)",
+                "soil 84 (mapped 50..98 -> 52)",
+                "fertilizer 84 (unmapped)",
+                "water 84 (unmapped)",
+                "light 77 (mapped 25..95 -> 18)",
+                "temperature 45 (mapped 77..100 -> 45)",
+                "humidity 46 (mapped 0..69 -> 1)",
+                "location 46 (unmapped)",
+            ]
+        );
+        assert_eq!(solve(input), Ok(46));
+    }
+}
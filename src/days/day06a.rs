@@ -0,0 +1,38 @@
+use crate::parse::{self, ParseError};
+
+fn parse_numbers(line: &str) -> Result<Vec<u64>, ParseError> {
+    line.split_whitespace().skip(1).map(parse::number).collect()
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let input = input.trim();
+    let lines: Vec<&str> = input.split("\n").collect();
+    let mut lines = lines.into_iter();
+    let time = parse_numbers(parse::next(&mut lines, input, "time line")?)?;
+    let dist = parse_numbers(parse::next(&mut lines, input, "distance line")?)?;
+    let ans = time
+        .iter()
+        .zip(dist.iter())
+        .map(|(&t, &d)| (1..t).filter(|speed| (t - speed) * speed > d).count() as u64)
+        .reduce(|a, b| a * b)
+        .ok_or_else(|| ParseError::missing(input, "races"))?;
+    Ok(ans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            Time:      7  15   30
+            Distance:  9  40  200";
+        assert_eq!(solve(input), Ok(288));
+        assert_eq!(
+            solve("Time:\nDistance:").unwrap_err().message,
+            "missing races"
+        );
+        assert_eq!(solve("Time: 3\nDistance: 100"), Ok(0));
+    }
+}
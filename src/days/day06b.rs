@@ -0,0 +1,24 @@
+use crate::parse::{self, ParseError};
+
+/// Reads the numbers after the label as one number, ignoring the spaces
+/// between them.
+fn parse_kerned(line: &str) -> Result<u64, ParseError> {
+    let mut value = 0;
+    for part in line.split_whitespace().skip(1) {
+        let n: u64 = parse::number(part)?;
+        value = value * 10u64.pow(part.len() as u32) + n;
+    }
+    Ok(value)
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let input = input.trim();
+    let lines: Vec<&str> = input.split("\n").collect();
+    let mut lines = lines.into_iter();
+    let time = parse_kerned(parse::next(&mut lines, input, "time line")?)?;
+    let dist = parse_kerned(parse::next(&mut lines, input, "distance line")?)?;
+    let wins = (1..time)
+        .filter(|speed| (time - speed) * speed > dist)
+        .count();
+    Ok(wins as u64)
+}
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, ParseError};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Label {
+    Ace,
+    King,
+    Queen,
+    Jack,
+    Ten,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    Two,
+}
+
+impl Label {
+    fn value(&self) -> u8 {
+        match self {
+            Label::Ace => 14,
+            Label::King => 13,
+            Label::Queen => 12,
+            Label::Jack => 11,
+            Label::Ten => 10,
+            Label::Nine => 9,
+            Label::Eight => 8,
+            Label::Seven => 7,
+            Label::Six => 6,
+            Label::Five => 5,
+            Label::Four => 4,
+            Label::Three => 3,
+            Label::Two => 2,
+        }
+    }
+
+    fn from_char(c: char) -> Option<Label> {
+        match c {
+            'A' => Some(Label::Ace),
+            'K' => Some(Label::King),
+            'Q' => Some(Label::Queen),
+            'J' => Some(Label::Jack),
+            'T' => Some(Label::Ten),
+            '9' => Some(Label::Nine),
+            '8' => Some(Label::Eight),
+            '7' => Some(Label::Seven),
+            '6' => Some(Label::Six),
+            '5' => Some(Label::Five),
+            '4' => Some(Label::Four),
+            '3' => Some(Label::Three),
+            '2' => Some(Label::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Ord for Label {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl PartialOrd for Label {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Strength {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl Strength {
+    fn value(&self) -> u8 {
+        match self {
+            Strength::FiveOfAKind => 8,
+            Strength::FourOfAKind => 7,
+            Strength::FullHouse => 6,
+            Strength::ThreeOfAKind => 5,
+            Strength::TwoPair => 4,
+            Strength::OnePair => 3,
+            Strength::HighCard => 2,
+        }
+    }
+}
+
+impl Ord for Strength {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl PartialOrd for Strength {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq)]
+struct Hand {
+    cards: [Label; 5],
+}
+
+impl Hand {
+    fn get_strength(&self) -> Strength {
+        let mut counts = HashMap::new();
+        for card in self.cards.iter() {
+            let count = counts.entry(card).or_insert(0);
+            *count += 1;
+        }
+        let mut counts = counts.into_values().collect::<Vec<u32>>();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts[..] {
+            [5] => Strength::FiveOfAKind,
+            [4, ..] => Strength::FourOfAKind,
+            [3, 2] => Strength::FullHouse,
+            [3, ..] => Strength::ThreeOfAKind,
+            [2, 2, ..] => Strength::TwoPair,
+            [2, ..] => Strength::OnePair,
+            _ => Strength::HighCard,
+        }
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // First compare on strength, then if they have equal strength, compare on card value from first to last card
+        self.get_strength()
+            .cmp(&other.get_strength())
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+struct Play {
+    hand: Hand,
+    bid: u32,
+}
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let plays: Vec<Play> = parse::lines(input, |line| {
+        let mut parts = line.split_whitespace();
+        let hand = parse::next(&mut parts, line, "hand")?;
+        let bid = parse::number(parse::next(&mut parts, line, "bid")?)?;
+        let cards = hand
+            .char_indices()
+            .map(|(i, c)| {
+                Label::from_char(c)
+                    .ok_or_else(|| ParseError::new(&hand[i..i + c.len_utf8()], "invalid card"))
+            })
+            .collect::<Result<Vec<Label>, _>>()?;
+        let cards = cards
+            .try_into()
+            .map_err(|_| ParseError::new(hand, "expected five cards in"))?;
+        Ok(Play {
+            hand: Hand { cards },
+            bid,
+        })
+    })?;
+    let mut plays = plays.iter().collect::<Vec<&Play>>();
+    plays.sort();
+    plays
+        .iter()
+        .enumerate()
+        .map(|(i, play)| (i as u32 + 1) * play.bid)
+        .reduce(|a, b| a + b)
+        .ok_or_else(|| ParseError::missing(input, "hands"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_strength() {
+        // Test case 1: Five of a kind
+        let hand = Hand {
+            cards: [Label::Ace, Label::Ace, Label::Ace, Label::Ace, Label::Ace],
+        };
+        assert_eq!(hand.get_strength(), Strength::FiveOfAKind);
+
+        // Test case 2: Four of a kind
+        let hand = Hand {
+            cards: [Label::Ace, Label::Ace, Label::Ace, Label::Ace, Label::King],
+        };
+        assert_eq!(hand.get_strength(), Strength::FourOfAKind);
+
+        // Test case 3: Full house
+        let hand = Hand {
+            cards: [Label::Ace, Label::Ace, Label::Ace, Label::King, Label::King],
+        };
+        assert_eq!(hand.get_strength(), Strength::FullHouse);
+
+        // Test case 4: Three of a kind
+        let hand = Hand {
+            cards: [
+                Label::Ace,
+                Label::Ace,
+                Label::Ace,
+                Label::King,
+                Label::Queen,
+            ],
+        };
+        assert_eq!(hand.get_strength(), Strength::ThreeOfAKind);
+
+        // Test case 5: Two pair
+        let hand = Hand {
+            cards: [
+                Label::Ace,
+                Label::Ace,
+                Label::King,
+                Label::King,
+                Label::Queen,
+            ],
+        };
+        assert_eq!(hand.get_strength(), Strength::TwoPair);
+
+        // Test case 6: One pair
+        let hand = Hand {
+            cards: [
+                Label::Ace,
+                Label::Ace,
+                Label::King,
+                Label::Queen,
+                Label::Jack,
+            ],
+        };
+        assert_eq!(hand.get_strength(), Strength::OnePair);
+
+        // Test case 7: High card
+        let hand = Hand {
+            cards: [
+                Label::Ace,
+                Label::King,
+                Label::Queen,
+                Label::Jack,
+                Label::Ten,
+            ],
+        };
+        assert_eq!(hand.get_strength(), Strength::HighCard);
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            32T3K 765
+            T55J5 684
+            KK677 28
+            KTJJT 220
+            QQQJA 483";
+        assert_eq!(solve(input), Ok(6440));
+        assert_eq!(solve("").unwrap_err().to_string(), "missing hands");
+    }
+}
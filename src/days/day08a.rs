@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, ParseError};
+
+type Network<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Network<'_>), ParseError> {
+    let input = input.trim();
+    let mut lines = input.split("\n").map(str::trim);
+    let dirs = parse::next(&mut lines, input, "directions")?;
+    if let Some((i, c)) = dirs.char_indices().find(|&(_, c)| c != 'L' && c != 'R') {
+        return Err(ParseError::new(
+            &dirs[i..i + c.len_utf8()],
+            "invalid direction",
+        ));
+    }
+    let mut graph: Network = HashMap::new();
+    for line in lines.filter(|line| !line.is_empty()) {
+        let (node, targets) = parse::split_once(line, " = ")?;
+        let (left, right) = targets
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .and_then(|t| t.split_once(", "))
+            .ok_or_else(|| ParseError::new(targets, "expected '(left, right)', found"))?;
+        graph.insert(node, (left, right));
+    }
+    for &target in graph.values().flat_map(|(left, right)| [left, right]) {
+        if !graph.contains_key(target) {
+            return Err(ParseError::new(target, "unknown node"));
+        }
+    }
+    Ok((dirs.chars().collect(), graph))
+}
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let (dirs, graph) = parse_input(input)?;
+    if !graph.contains_key("AAA") {
+        return Err(ParseError::missing(input.trim(), "node AAA"));
+    }
+    let mut node = "AAA";
+    let mut steps: u32 = 0;
+    while node != "ZZZ" {
+        let dir_index = steps as usize % dirs.len();
+        let dir = dirs[dir_index];
+        let (left, right) = graph.get(node).unwrap();
+        match dir {
+            'L' => node = left,
+            'R' => node = right,
+            _ => panic!("Invalid direction"),
+        }
+        steps += 1;
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            RL
+
+            AAA = (BBB, CCC)
+            BBB = (DDD, EEE)
+            CCC = (ZZZ, GGG)
+            DDD = (DDD, DDD)
+            EEE = (EEE, EEE)
+            GGG = (GGG, GGG)
+            ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(solve(input), Ok(2));
+        let input = "
+            LLR
+
+            AAA = (BBB, BBB)
+            BBB = (AAA, ZZZ)
+            ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(solve(input), Ok(6));
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::{
+    cycle,
+    parse::{self, ParseError},
+};
+
+type Network<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Network<'_>), ParseError> {
+    let input = input.trim();
+    let mut lines = input.split("\n").map(str::trim);
+    let dirs = parse::next(&mut lines, input, "directions")?;
+    if let Some((i, c)) = dirs.char_indices().find(|&(_, c)| c != 'L' && c != 'R') {
+        return Err(ParseError::new(
+            &dirs[i..i + c.len_utf8()],
+            "invalid direction",
+        ));
+    }
+    let mut graph: Network = HashMap::new();
+    for line in lines.filter(|line| !line.is_empty()) {
+        let (node, targets) = parse::split_once(line, " = ")?;
+        let (left, right) = targets
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .and_then(|t| t.split_once(", "))
+            .ok_or_else(|| ParseError::new(targets, "expected '(left, right)', found"))?;
+        graph.insert(node, (left, right));
+    }
+    for &target in graph.values().flat_map(|(left, right)| [left, right]) {
+        if !graph.contains_key(target) {
+            return Err(ParseError::new(target, "unknown node"));
+        }
+    }
+    Ok((dirs.chars().collect(), graph))
+}
+
+/// When a ghost is on an end node, once it has settled into its loop.
+struct Ghost {
+    /// The first step of the loop.
+    settled: u64,
+    /// The steps in `settled..settled + period` that end on an end node,
+    /// repeating every `period` steps after.
+    ends: Vec<u64>,
+    period: u64,
+}
+
+fn ghost<'a>(dirs: &[char], graph: &Network<'a>, node: &'a str) -> Result<Ghost, ParseError> {
+    let step = |&(node, i): &(&'a str, usize)| {
+        let (left, right) = graph[node];
+        let next = if dirs[i] == 'L' { left } else { right };
+        (next, (i + 1) % dirs.len())
+    };
+    let start = (node, 0);
+    let cycle = cycle::brent(&start, step);
+    let mut state = cycle.state_at(&start, step, cycle.start);
+    let mut ends = Vec::new();
+    for n in 0..cycle.len {
+        if state.0.ends_with('Z') {
+            ends.push(n);
+        }
+        state = step(&state);
+    }
+    if ends.is_empty() {
+        return Err(ParseError::unsolvable(format!(
+            "ghost starting at {} never reaches an end node",
+            node
+        )));
+    }
+    // The ends can repeat more often than the whole state does, when the
+    // loop passes an end node at different points in the directions.
+    let period = (1..=cycle.len)
+        .filter(|p| cycle.len.is_multiple_of(*p))
+        .find(|p| {
+            ends.iter()
+                .all(|n| ends.binary_search(&((n + p) % cycle.len)).is_ok())
+        })
+        .unwrap();
+    let settled = cycle.start as u64;
+    Ok(Ghost {
+        settled,
+        ends: ends
+            .into_iter()
+            .take_while(|&n| n < period)
+            .map(|n| settled + n as u64)
+            .collect(),
+        period: period as u64,
+    })
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let (dirs, graph) = parse_input(input)?;
+    let mut start_nodes: Vec<&str> = graph
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .copied()
+        .collect();
+    start_nodes.sort_unstable();
+    if start_nodes.is_empty() {
+        return Err(ParseError::missing(input, "start nodes"));
+    }
+
+    // Combine the ghosts one at a time, keeping every step, modulo the
+    // combined period, at which all of them so far are on end nodes.
+    let (mut times, mut period): (Vec<u64>, u64) = (vec![0], 1);
+    let mut settled = 0;
+    for node in start_nodes {
+        let ghost = ghost(&dirs, &graph, node)?;
+        let gcd = num::integer::gcd(period, ghost.period);
+        let mut next = Vec::new();
+        for &time in &times {
+            for &end in &ghost.ends {
+                if time.abs_diff(end) % gcd != 0 {
+                    continue;
+                }
+                let mut time = time;
+                while time % ghost.period != end % ghost.period {
+                    time += period;
+                }
+                next.push(time);
+            }
+        }
+        if next.is_empty() {
+            return Err(ParseError::unsolvable(format!(
+                "ghost starting at {} never lines up with the others",
+                node
+            )));
+        }
+        period = num::integer::lcm(period, ghost.period);
+        times = next.into_iter().map(|time| time % period).collect();
+        times.sort_unstable();
+        times.dedup();
+        settled = settled.max(ghost.settled);
+    }
+    Ok(times
+        .into_iter()
+        .map(|time| time + settled.saturating_sub(time).div_ceil(period) * period)
+        .min()
+        .unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+        LR
+
+        11A = (11B, XXX)
+        11B = (XXX, 11Z)
+        11Z = (11B, XXX)
+        22A = (22B, XXX)
+        22B = (22C, 22C)
+        22C = (22Z, 22Z)
+        22Z = (22B, 22B)
+        XXX = (XXX, XXX)";
+        assert_eq!(solve(input), Ok(6));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::parse::{self, ParseError};
+
+fn extrapolate(input: &[i64]) -> i64 {
+    let is_all_zeros = input.iter().all(|&x| x == 0);
+    if is_all_zeros {
+        return 0;
+    }
+    let diffs: Vec<i64> = input.windows(2).map(|x| x[1] - x[0]).collect();
+    let first_value = input.first().unwrap();
+    let diff = extrapolate(&diffs);
+    first_value - diff
+}
+
+pub fn solve(input: &str) -> Result<i64, ParseError> {
+    let input: Vec<Vec<i64>> = parse::lines(input, |line| {
+        line.split_whitespace().map(parse::number).collect()
+    })?;
+    let ans: i64 = input.iter().map(|values| extrapolate(values)).sum();
+    Ok(ans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45";
+        assert_eq!(solve(input), Ok(2));
+    }
+}
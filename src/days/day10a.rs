@@ -0,0 +1,209 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    geometry::Direction,
+    grid::{Grid, Pos},
+    parse::ParseError,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Copy)]
+enum Pipe {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+}
+
+impl Pipe {
+    fn get_next_dir(&self, dir: Direction) -> Option<Direction> {
+        match self {
+            Pipe::NS => match dir {
+                Direction::North => Some(Direction::South),
+                Direction::South => Some(Direction::North),
+                _ => None,
+            },
+            Pipe::EW => match dir {
+                Direction::East => Some(Direction::West),
+                Direction::West => Some(Direction::East),
+                _ => None,
+            },
+            Pipe::NE => match dir {
+                Direction::North => Some(Direction::East),
+                Direction::East => Some(Direction::North),
+                _ => None,
+            },
+            Pipe::NW => match dir {
+                Direction::North => Some(Direction::West),
+                Direction::West => Some(Direction::North),
+                _ => None,
+            },
+            Pipe::SW => match dir {
+                Direction::South => Some(Direction::West),
+                Direction::West => Some(Direction::South),
+                _ => None,
+            },
+            Pipe::SE => match dir {
+                Direction::South => Some(Direction::East),
+                Direction::East => Some(Direction::South),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+enum TileItem {
+    Ground,
+    Pipe(Pipe),
+    Start,
+}
+
+impl TileItem {
+    fn from_str(c: char) -> Result<TileItem, ParseError> {
+        let item = match c {
+            '.' => TileItem::Ground,
+            '|' => TileItem::Pipe(Pipe::NS),
+            '-' => TileItem::Pipe(Pipe::EW),
+            'L' => TileItem::Pipe(Pipe::NE),
+            'J' => TileItem::Pipe(Pipe::NW),
+            '7' => TileItem::Pipe(Pipe::SW),
+            'F' => TileItem::Pipe(Pipe::SE),
+            'S' => TileItem::Start,
+            _ => return Err(ParseError::invalid_char(c, "tile")),
+        };
+        Ok(item)
+    }
+
+    fn to_str(&self) -> &str {
+        match self {
+            TileItem::Ground => ".",
+            TileItem::Pipe(Pipe::NS) => "|",
+            TileItem::Pipe(Pipe::EW) => "-",
+            TileItem::Pipe(Pipe::NE) => "L",
+            TileItem::Pipe(Pipe::NW) => "J",
+            TileItem::Pipe(Pipe::SW) => "7",
+            TileItem::Pipe(Pipe::SE) => "F",
+            TileItem::Start => "S",
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct Tile {
+    item: TileItem,
+    pos: Pos,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.item.to_str())
+    }
+}
+
+impl Tile {
+    fn get_neighbor(&self, dir: &Direction, map: &Map) -> Option<Tile> {
+        let pos = map.step(self.pos, *dir)?;
+        Some(map[pos].clone())
+    }
+}
+
+type Map = Grid<Tile>;
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let map: Map = Grid::try_parse(input, TileItem::from_str)?.map_with_pos(|pos, item| Tile {
+        item: item.clone(),
+        pos,
+    });
+    let start = map
+        .iter()
+        .map(|(_, tile)| tile)
+        .find(|tile| tile.item == TileItem::Start)
+        .ok_or_else(|| ParseError::missing(input.trim(), "start tile 'S'"))?
+        .clone();
+    for try_dir in Direction::ALL {
+        let mut found_start = false;
+        let mut steps = 1;
+        let Some(mut current) = start.get_neighbor(&try_dir, &map) else {
+            continue;
+        };
+        match current.item {
+            TileItem::Pipe(_) => (),
+            _ => continue,
+        };
+        let mut dir = try_dir;
+        loop {
+            let pipe = match current.item {
+                TileItem::Pipe(pipe) => pipe,
+                TileItem::Start => {
+                    found_start = true;
+                    break;
+                }
+                // The pipe leads onto the ground, so this is no loop.
+                _ => break,
+            };
+            dir = dir.opposite();
+            dir = match pipe.get_next_dir(dir) {
+                Some(next_dir) => next_dir,
+                None => break,
+            };
+            match current.get_neighbor(&dir, &map) {
+                Some(next) => current = next,
+                None => break,
+            };
+            steps += 1;
+        }
+        if found_start {
+            return Ok(steps / 2);
+        }
+    }
+    Err(ParseError::unsolvable(
+        "no loop of pipes runs through the start",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            .....
+            .S-7.
+            .|.|.
+            .L-J.
+            .....";
+        assert_eq!(solve(input), Ok(4));
+        let input = "
+            -L|F7
+            7S-7|
+            L|7||
+            -L-J|
+            L|-JF";
+        assert_eq!(solve(input), Ok(4));
+        let input = "
+            ..F7.
+            .FJ|.
+            SJ.L7
+            |F--J
+            LJ...";
+        assert_eq!(solve(input), Ok(8));
+        let input = "
+            7-F7-
+            .FJ|7
+            SJLL7
+            |F--J
+            LJ.LJ";
+        assert_eq!(solve(input), Ok(8));
+
+        let err = solve("").unwrap_err();
+        assert_eq!(err.to_string(), "missing start tile 'S'");
+        let err = solve("S-.").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no solution: no loop of pipes runs through the start"
+        );
+    }
+}
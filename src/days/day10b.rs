@@ -0,0 +1,307 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::{
+    geometry::Direction,
+    grid::{Grid, Pos},
+    parse::ParseError,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Copy)]
+enum Pipe {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+}
+
+impl Pipe {
+    fn get_next_dir(&self, dir: Direction) -> Option<Direction> {
+        match self {
+            Pipe::NS => match dir {
+                Direction::North => Some(Direction::South),
+                Direction::South => Some(Direction::North),
+                _ => None,
+            },
+            Pipe::EW => match dir {
+                Direction::East => Some(Direction::West),
+                Direction::West => Some(Direction::East),
+                _ => None,
+            },
+            Pipe::NE => match dir {
+                Direction::North => Some(Direction::East),
+                Direction::East => Some(Direction::North),
+                _ => None,
+            },
+            Pipe::NW => match dir {
+                Direction::North => Some(Direction::West),
+                Direction::West => Some(Direction::North),
+                _ => None,
+            },
+            Pipe::SW => match dir {
+                Direction::South => Some(Direction::West),
+                Direction::West => Some(Direction::South),
+                _ => None,
+            },
+            Pipe::SE => match dir {
+                Direction::South => Some(Direction::East),
+                Direction::East => Some(Direction::South),
+                _ => None,
+            },
+        }
+    }
+
+    fn from_dirs(dir1: Direction, dir2: Direction) -> Option<Pipe> {
+        let pipe = match (dir1, dir2) {
+            (Direction::North, Direction::South) => Pipe::NS,
+            (Direction::South, Direction::North) => Pipe::NS,
+            (Direction::East, Direction::West) => Pipe::EW,
+            (Direction::West, Direction::East) => Pipe::EW,
+            (Direction::North, Direction::East) => Pipe::NE,
+            (Direction::East, Direction::North) => Pipe::NE,
+            (Direction::North, Direction::West) => Pipe::NW,
+            (Direction::West, Direction::North) => Pipe::NW,
+            (Direction::South, Direction::West) => Pipe::SW,
+            (Direction::West, Direction::South) => Pipe::SW,
+            (Direction::South, Direction::East) => Pipe::SE,
+            (Direction::East, Direction::South) => Pipe::SE,
+            _ => return None,
+        };
+        Some(pipe)
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+enum GroundType {
+    Inside,
+    Outside,
+    Unknown,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+enum TileItem {
+    Ground(GroundType),
+    Pipe(Pipe),
+    Start,
+}
+
+impl TileItem {
+    fn from_str(c: char) -> Result<TileItem, ParseError> {
+        let item = match c {
+            '.' => TileItem::Ground(GroundType::Unknown),
+            'O' => TileItem::Ground(GroundType::Outside),
+            'I' => TileItem::Ground(GroundType::Inside),
+            '|' => TileItem::Pipe(Pipe::NS),
+            '-' => TileItem::Pipe(Pipe::EW),
+            'L' => TileItem::Pipe(Pipe::NE),
+            'J' => TileItem::Pipe(Pipe::NW),
+            '7' => TileItem::Pipe(Pipe::SW),
+            'F' => TileItem::Pipe(Pipe::SE),
+            'S' => TileItem::Start,
+            _ => return Err(ParseError::invalid_char(c, "tile")),
+        };
+        Ok(item)
+    }
+
+    fn to_str(&self) -> &str {
+        match self {
+            TileItem::Ground(GroundType::Unknown) => ".",
+            TileItem::Ground(GroundType::Outside) => "O",
+            TileItem::Ground(GroundType::Inside) => "I",
+            TileItem::Pipe(Pipe::NS) => "|",
+            TileItem::Pipe(Pipe::EW) => "-",
+            TileItem::Pipe(Pipe::NE) => "L",
+            TileItem::Pipe(Pipe::NW) => "J",
+            TileItem::Pipe(Pipe::SW) => "7",
+            TileItem::Pipe(Pipe::SE) => "F",
+            TileItem::Start => "S",
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct Tile {
+    item: TileItem,
+    pos: Pos,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.item.to_str())
+    }
+}
+
+impl Tile {
+    fn get_neighbor(&self, dir: &Direction, map: &Map) -> Option<Tile> {
+        let pos = map.step(self.pos, *dir)?;
+        Some(map[pos].clone())
+    }
+}
+
+type Map = Grid<Tile>;
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let mut map: Map = Grid::try_parse(input, TileItem::from_str)?.map_with_pos(|pos, item| Tile {
+        item: item.clone(),
+        pos,
+    });
+    let start = map
+        .iter()
+        .map(|(_, tile)| tile)
+        .find(|tile| tile.item == TileItem::Start)
+        .ok_or_else(|| ParseError::missing(input.trim(), "start tile 'S'"))?
+        .clone();
+    for try_dir in Direction::ALL {
+        let mut path = Vec::new();
+        let mut found_start = false;
+        let mut current = match start.get_neighbor(&try_dir, &map) {
+            Some(tile) => tile,
+            None => continue,
+        };
+        match current.item {
+            TileItem::Pipe(_) => (),
+            _ => continue,
+        };
+        let mut dir = try_dir;
+        loop {
+            path.push(current.pos);
+            let pipe = match current.item {
+                TileItem::Pipe(pipe) => pipe,
+                TileItem::Start => {
+                    found_start = true;
+                    break;
+                }
+                // The pipe leads onto the ground, so this is no loop.
+                _ => break,
+            };
+            dir = dir.opposite();
+            dir = match pipe.get_next_dir(dir) {
+                Some(next_dir) => next_dir,
+                None => {
+                    break;
+                }
+            };
+            match current.get_neighbor(&dir, &map) {
+                Some(next) => current = next,
+                None => {
+                    break;
+                }
+            };
+        }
+        if !found_start {
+            continue;
+        }
+
+        // Replace start with appropriate pipe and remove dead ends
+        let start_dir = try_dir;
+        let end_dir = dir;
+        let Some(start_pipe) = Pipe::from_dirs(start_dir, end_dir.opposite()) else {
+            continue;
+        };
+        let path: HashSet<Pos> = path.into_iter().collect();
+        for pos in map.positions() {
+            let tile = &mut map[pos];
+            match tile.item {
+                TileItem::Pipe(_) if !path.contains(&pos) => {
+                    tile.item = TileItem::Ground(GroundType::Unknown);
+                }
+                TileItem::Start => tile.item = TileItem::Pipe(start_pipe),
+                _ => (),
+            }
+        }
+        // Fill in inside/outside
+        let mut inside = 0;
+        for y in 0..map.height() {
+            let mut count = 0;
+            for x in 0..map.width() {
+                let tile = &mut map[(x, y)];
+                match tile.item {
+                    TileItem::Pipe(Pipe::NS) => count += 1,
+                    TileItem::Pipe(Pipe::NE) => count += 1,
+                    TileItem::Pipe(Pipe::NW) => count += 1,
+                    TileItem::Pipe(_) => (),
+                    TileItem::Ground(GroundType::Unknown) => {
+                        if count % 2 == 0 {
+                            tile.item = TileItem::Ground(GroundType::Outside);
+                        } else {
+                            tile.item = TileItem::Ground(GroundType::Inside);
+                            inside += 1;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        return Ok(inside);
+    }
+    Err(ParseError::unsolvable(
+        "no loop of pipes runs through the start",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            ...........
+            .S-------7.
+            .|F-----7|.
+            .||.....||.
+            .||.....||.
+            .|L-7.F-J|.
+            .|..|.|..|.
+            .L--J.L--J.
+            ...........";
+        assert_eq!(solve(input), Ok(4));
+        let input = "
+            ..........
+            .S------7.
+            .|F----7|.
+            .||....||.
+            .||....||.
+            .|L-7F-J|.
+            .|..||..|.
+            .L--JL--J.
+            ..........";
+        assert_eq!(solve(input), Ok(4));
+        let input = "
+            .F----7F7F7F7F-7....
+            .|F--7||||||||FJ....
+            .||.FJ||||||||L7....
+            FJL7L7LJLJ||LJ.L-7..
+            L--J.L7...LJS7F-7L7.
+            ....F-J..F7FJ|L7L7L7
+            ....L7.F7||L7|.L7L7|
+            .....|FJLJ|FJ|F7|.LJ
+            ....FJL-7.||.||||...
+            ....L---J.LJ.LJLJ...";
+        assert_eq!(solve(input), Ok(8));
+        let input = "
+            FF7FSF7F7F7F7F7F---7
+            L|LJ||||||||||||F--J
+            FL-7LJLJ||||||LJL-77
+            F--JF--7||LJLJ7F7FJ-
+            L---JF-JLJ.||-FJLJJ7
+            |F|F-JF---7F7-L7L|7|
+            |FFJF7L7F-JF7|JL---7
+            7-L-JL7||F7|L7F-7F7|
+            L.L7LFJ|||||FJL7||LJ
+            L7JLJL-JLJLJL--JLJ.L";
+        assert_eq!(solve(input), Ok(10));
+
+        let err = solve("").unwrap_err();
+        assert_eq!(err.to_string(), "missing start tile 'S'");
+        let err = solve("S-.").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no solution: no loop of pipes runs through the start"
+        );
+    }
+}
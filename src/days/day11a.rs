@@ -0,0 +1,150 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::{geometry::Point2, grid::Grid, parse::ParseError};
+
+#[derive(PartialEq, Eq, Clone)]
+enum ImagePoint {
+    EmptySpace,
+    Galaxy,
+}
+
+impl ImagePoint {
+    fn from_char(c: char) -> Result<ImagePoint, ParseError> {
+        match c {
+            '.' => Ok(ImagePoint::EmptySpace),
+            '#' => Ok(ImagePoint::Galaxy),
+            _ => Err(ParseError::invalid_char(c, "point")),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            ImagePoint::EmptySpace => '.',
+            ImagePoint::Galaxy => '#',
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct Image {
+    points: Grid<ImagePoint>,
+}
+
+impl Image {
+    fn from_string(s: &str) -> Result<Image, ParseError> {
+        Ok(Image {
+            points: Grid::try_parse(s, ImagePoint::from_char)?,
+        })
+    }
+
+    fn expand(&mut self) {
+        let mut cur_row = 0;
+        while cur_row < self.points.height() {
+            let row_is_all_empty = self
+                .points
+                .row(cur_row)
+                .iter()
+                .all(|p| *p == ImagePoint::EmptySpace);
+            if row_is_all_empty {
+                let width = self.points.width();
+                self.points
+                    .insert_row(cur_row, vec![ImagePoint::EmptySpace; width]);
+                cur_row += 1;
+            }
+            cur_row += 1;
+        }
+        let mut cur_col = 0;
+        while cur_col < self.points.width() {
+            let col_is_all_empty = self
+                .points
+                .column(cur_col)
+                .all(|p| *p == ImagePoint::EmptySpace);
+            if col_is_all_empty {
+                let height = self.points.height();
+                self.points
+                    .insert_column(cur_col, vec![ImagePoint::EmptySpace; height]);
+                cur_col += 1;
+            }
+            cur_col += 1;
+        }
+    }
+
+    fn get_galaxies(&self) -> Vec<Point2> {
+        self.points
+            .iter()
+            .filter(|(_, p)| **p == ImagePoint::Galaxy)
+            .map(|((x, y), _)| Point2::new(x as i64, y as i64))
+            .collect()
+    }
+}
+
+impl Display for ImagePoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.points)
+    }
+}
+
+impl Debug for Image {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let mut image = Image::from_string(input)?;
+    image.expand();
+    let galaxies = image.get_galaxies();
+    // for each pair of galaxies, find the manhattan distance between them
+    let mut ans = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            let dist = galaxies[i].manhattan(galaxies[j]);
+            ans += dist as u64;
+        }
+    }
+    Ok(ans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....";
+        let input2 = "
+            ....#........
+            .........#...
+            #............
+            .............
+            .............
+            ........#....
+            .#...........
+            ............#
+            .............
+            .............
+            .........#...
+            #....#.......";
+        let mut image = Image::from_string(input).unwrap();
+        image.expand();
+        let image2 = Image::from_string(input2).unwrap();
+        assert_eq!(image, image2);
+        assert_eq!(solve(input), Ok(374));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{geometry::Point2, grid::Grid, parse::ParseError};
+
+pub fn solve(input: &str, expansion_ratio: usize) -> Result<u64, ParseError> {
+    let expansion_ratio = expansion_ratio as i64;
+    let image = Grid::try_parse(input, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseError::invalid_char(c, "point")),
+    })?;
+    let mut galaxies: Vec<Point2> = image
+        .iter()
+        .filter(|(_, &is_galaxy)| is_galaxy)
+        .map(|((x, y), _)| Point2::new(x as i64, y as i64))
+        .collect();
+    if galaxies.is_empty() {
+        return Err(ParseError::missing(input, "galaxies"));
+    }
+
+    // Expand empty rows and columns by the expansion ratio
+    let mut max_x = galaxies.iter().map(|p| p.x).max().unwrap_or_default();
+    let mut max_y = galaxies.iter().map(|p| p.y).max().unwrap_or_default();
+    let mut cur_row = 0;
+    while cur_row <= max_y {
+        let row_is_all_empty = galaxies.iter().all(|p| p.y != cur_row);
+        if row_is_all_empty {
+            galaxies.iter_mut().for_each(|p| {
+                if p.y > cur_row {
+                    p.y += expansion_ratio - 1;
+                }
+            });
+            max_y += expansion_ratio;
+            cur_row += expansion_ratio;
+        }
+        cur_row += 1;
+    }
+    let mut cur_col = 0;
+    while cur_col <= max_x {
+        let col_is_all_empty = galaxies.iter().all(|p| p.x != cur_col);
+        if col_is_all_empty {
+            galaxies.iter_mut().for_each(|p| {
+                if p.x > cur_col {
+                    p.x += expansion_ratio - 1;
+                }
+            });
+            max_x += expansion_ratio;
+            cur_col += expansion_ratio;
+        }
+        cur_col += 1;
+    }
+
+    // for each pair of galaxies, find the manhattan distance between them
+    let mut ans = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            let dist = galaxies[i].manhattan(galaxies[j]);
+            ans += dist as u64;
+            //println!("{} -> {} = {}", i + 1, j + 1, dist);
+        }
+    }
+    Ok(ans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....";
+        assert_eq!(solve(input, 2), Ok(374));
+        assert_eq!(solve(input, 10), Ok(1030));
+        assert_eq!(solve(input, 100), Ok(8410));
+
+        let err = solve("", 2).unwrap_err();
+        assert_eq!(err.to_string(), "missing galaxies");
+    }
+}
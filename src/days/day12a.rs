@@ -0,0 +1,435 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::AddAssign,
+    str::FromStr,
+};
+
+use crate::parse::{self, ParseError};
+
+/// A spring's condition. `Operational` is written `#` and `Damaged` `.`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+/// One line of the input: the springs and the lengths of the runs of
+/// operational ones, in order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Row {
+    pub springs: Vec<Spring>,
+    pub groups: Vec<usize>,
+}
+
+/// How much of the table a count filled in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Every `(spring_idx, group_idx)` state.
+    pub states: usize,
+    /// The states some partial arrangement reaches.
+    pub reachable: usize,
+}
+
+impl Spring {
+    pub fn symbol(self) -> char {
+        match self {
+            Spring::Operational => '#',
+            Spring::Damaged => '.',
+            Spring::Unknown => '?',
+        }
+    }
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let springs: String = self.springs.iter().map(|s| s.symbol()).collect();
+        let groups: Vec<_> = self.groups.iter().map(|n| n.to_string()).collect();
+        write!(f, "{} {}", springs, groups.join(","))
+    }
+}
+
+impl FromStr for Row {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (springs, groups) = parse::split_once(line, " ")?;
+        let springs = springs
+            .char_indices()
+            .map(|(i, c)| match c {
+                '#' => Ok(Spring::Operational),
+                '.' => Ok(Spring::Damaged),
+                '?' => Ok(Spring::Unknown),
+                _ => Err(ParseError::invalid_char(c, "spring").at(&springs[i..i + c.len_utf8()])),
+            })
+            .collect::<Result<_, _>>()?;
+        let groups = groups
+            .split(',')
+            .map(|group| match parse::number(group)? {
+                0 => Err(ParseError::new(group, "empty group")),
+                len => Ok(len),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Row { springs, groups })
+    }
+}
+
+impl AddAssign for CacheStats {
+    fn add_assign(&mut self, other: CacheStats) {
+        self.states += other.states;
+        self.reachable += other.reachable;
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} states reachable ({:.1}%)",
+            self.reachable,
+            self.states,
+            100.0 * self.reachable as f64 / self.states.max(1) as f64
+        )
+    }
+}
+
+impl Row {
+    /// The row repeated `factor` times, with an unknown spring between each
+    /// copy of the springs.
+    pub fn unfold(&self, factor: usize) -> Row {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * factor);
+        for i in 0..factor {
+            if i > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Row {
+            springs,
+            groups: self.groups.repeat(factor),
+        }
+    }
+
+    /// Whether group `g` can start at spring `i`: it fits before the end,
+    /// every spring it covers could be operational and the one after it
+    /// could be damaged.
+    pub fn fits(&self, i: usize, g: usize) -> bool {
+        let end = i + self.groups[g];
+        end <= self.springs.len()
+            && self.springs[i..end].iter().all(|&s| s != Spring::Damaged)
+            && self.springs.get(end) != Some(&Spring::Operational)
+    }
+
+    /// The state a group starting at spring `i` leads to, skipping the
+    /// spring after it.
+    pub fn after_group(&self, i: usize, g: usize) -> usize {
+        (i + self.groups[g] + 1).min(self.springs.len())
+    }
+
+    /// Counts arrangements from left to right. `ways[i][g]` is the number
+    /// of ways to fill in the first `i` springs with the first `g` groups,
+    /// with spring `i` free to start a new group.
+    pub fn table(&self) -> Vec<Vec<u64>> {
+        let mut ways = Vec::new();
+        self.fill_table(&mut ways);
+        ways
+    }
+
+    /// Fills in `ways` as `table` would, reusing its memory.
+    fn fill_table(&self, ways: &mut Vec<Vec<u64>>) {
+        let (n, groups) = (self.springs.len(), self.groups.len());
+        ways.resize_with(n + 1, Vec::new);
+        for row in ways.iter_mut() {
+            row.clear();
+            row.resize(groups + 1, 0);
+        }
+        ways[0][0] = 1;
+        for i in 0..n {
+            for g in 0..=groups {
+                let count = ways[i][g];
+                if count == 0 {
+                    continue;
+                }
+                if self.springs[i] != Spring::Operational {
+                    ways[i + 1][g] += count;
+                }
+                if g < groups && self.fits(i, g) {
+                    ways[self.after_group(i, g)][g + 1] += count;
+                }
+            }
+        }
+    }
+
+    pub fn count(&self) -> (u64, CacheStats) {
+        self.count_with(&mut Vec::new())
+    }
+
+    /// Counts arrangements using `ways` for the table, so one table can
+    /// serve many rows.
+    pub fn count_with(&self, ways: &mut Vec<Vec<u64>>) -> (u64, CacheStats) {
+        self.fill_table(ways);
+        let stats = CacheStats {
+            states: ways.iter().map(Vec::len).sum(),
+            reachable: ways.iter().flatten().filter(|&&count| count > 0).count(),
+        };
+        (ways[self.springs.len()][self.groups.len()], stats)
+    }
+}
+
+/// Every arrangement of a row's springs, produced lazily as a string of `#`
+/// and `.`. Arrangements are built from the right along the table, only
+/// following steps that still lead somewhere, so each one costs time in
+/// proportion to its length.
+pub struct Arrangements<'a> {
+    row: &'a Row,
+    ways: Vec<Vec<u64>>,
+    /// States still to expand, with the springs after them filled in,
+    /// reversed.
+    pending: Vec<(usize, usize, Vec<char>)>,
+}
+
+/// Picks arrangements of a row uniformly at random, weighting each step by
+/// the number of arrangements it leads to.
+pub struct Sampler<'a> {
+    row: &'a Row,
+    ways: Vec<Vec<u64>>,
+}
+
+impl Row {
+    /// The states leading straight into `(i, g)` that some arrangement
+    /// passes through, in `ways`.
+    fn steps_into(&self, ways: &[Vec<u64>], i: usize, g: usize) -> Vec<(usize, usize)> {
+        let mut steps = Vec::with_capacity(3);
+        if i > 0 && self.springs[i - 1] != Spring::Operational && ways[i - 1][g] > 0 {
+            steps.push((i - 1, g));
+        }
+        if g > 0 {
+            let len = self.groups[g - 1];
+            // A group reaching the end may or may not have a spring after it.
+            let starts = [
+                i.checked_sub(len + 1),
+                (i == self.springs.len())
+                    .then(|| i.checked_sub(len))
+                    .flatten(),
+            ];
+            for j in starts.into_iter().flatten() {
+                if ways[j][g - 1] > 0 && self.after_group(j, g - 1) == i && self.fits(j, g - 1) {
+                    steps.push((j, g - 1));
+                }
+            }
+        }
+        steps
+    }
+
+    /// Writes the springs from `from` up to state `i`, reversed.
+    fn fill(&self, (from, g): (usize, usize), i: usize, g_to: usize, out: &mut Vec<char>) {
+        if g == g_to {
+            out.push('.');
+            return;
+        }
+        let end = from + self.groups[g];
+        if end < i {
+            out.push('.');
+        }
+        out.extend(std::iter::repeat_n('#', end - from));
+    }
+
+    pub fn arrangements(&self) -> Arrangements<'_> {
+        let ways = self.table();
+        let (n, groups) = (self.springs.len(), self.groups.len());
+        let pending = if ways[n][groups] > 0 {
+            vec![(n, groups, Vec::with_capacity(n))]
+        } else {
+            Vec::new()
+        };
+        Arrangements {
+            row: self,
+            ways,
+            pending,
+        }
+    }
+
+    pub fn sampler(&self) -> Sampler<'_> {
+        Sampler {
+            row: self,
+            ways: self.table(),
+        }
+    }
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while let Some((i, g, filled)) = self.pending.pop() {
+            if i == 0 {
+                return Some(filled.into_iter().rev().collect());
+            }
+            // Pushed in reverse so the first step is expanded first.
+            for step in self.row.steps_into(&self.ways, i, g).into_iter().rev() {
+                let mut filled = filled.clone();
+                self.row.fill(step, i, g, &mut filled);
+                self.pending.push((step.0, step.1, filled));
+            }
+        }
+        None
+    }
+}
+
+impl Sampler<'_> {
+    /// The number of arrangements there are to pick from.
+    pub fn count(&self) -> u64 {
+        self.ways[self.row.springs.len()][self.row.groups.len()]
+    }
+
+    /// One arrangement, or `None` if the row has none.
+    pub fn sample(&self, rng: &mut fastrand::Rng) -> Option<String> {
+        if self.count() == 0 {
+            return None;
+        }
+        let (mut i, mut g) = (self.row.springs.len(), self.row.groups.len());
+        let mut filled = Vec::with_capacity(i);
+        while i > 0 {
+            let steps = self.row.steps_into(&self.ways, i, g);
+            let mut pick = rng.u64(0..self.ways[i][g]);
+            let step = *steps
+                .iter()
+                .find(|&&(j, h)| {
+                    let found = pick < self.ways[j][h];
+                    pick = pick.saturating_sub(self.ways[j][h]);
+                    found
+                })
+                .unwrap();
+            self.row.fill(step, i, g, &mut filled);
+            (i, g) = step;
+        }
+        Some(filled.into_iter().rev().collect())
+    }
+}
+
+/// Counts each row after unfolding it, with one table for the whole run.
+pub fn count_rows(rows: &[Row], unfold: usize) -> Vec<(u64, CacheStats)> {
+    let mut ways = Vec::new();
+    rows.iter()
+        .map(|row| row.unfold(unfold).count_with(&mut ways))
+        .collect()
+}
+
+/// Counts each row after unfolding it, spread across threads with a table
+/// for each.
+#[cfg(feature = "parallel")]
+pub fn count_rows_parallel(rows: &[Row], unfold: usize) -> Vec<(u64, CacheStats)> {
+    use rayon::prelude::*;
+
+    rows.par_iter()
+        .map_init(Vec::new, |ways, row| row.unfold(unfold).count_with(ways))
+        .collect()
+}
+
+/// Sums the arrangements of every row after unfolding each `unfold` times.
+/// Rows are counted in parallel with the `parallel` feature.
+pub fn count_all(input: &str, unfold: usize) -> Result<(u64, CacheStats), ParseError> {
+    let rows: Vec<Row> = parse::lines(input, str::parse)?;
+    let mut stats = CacheStats::default();
+    let mut total = 0;
+    #[cfg(not(feature = "parallel"))]
+    let counts = count_rows(&rows, unfold);
+    #[cfg(feature = "parallel")]
+    let counts = count_rows_parallel(&rows, unfold);
+    for (count, row_stats) in counts {
+        total += count;
+        stats += row_stats;
+    }
+    Ok((total, stats))
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    count_all(input, 1).map(|(total, _)| total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(line: &str) -> u64 {
+        line.parse::<Row>().unwrap().count().0
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(count("???.### 1,1,3"), 1);
+        assert_eq!(count(".??..??...?##. 1,1,3"), 4);
+        assert_eq!(count("?#?#?#?#?#?#?#? 1,3,1,6"), 1);
+        assert_eq!(count("????.#...#... 4,1,1"), 1);
+        assert_eq!(count("????.######..#####. 1,6,5"), 4);
+        assert_eq!(count("?###???????? 3,2,1"), 10);
+        let input = "
+            ???.### 1,1,3
+            .??..??...?##. 1,1,3
+            ?#?#?#?#?#?#?#? 1,3,1,6
+            ????.#...#... 4,1,1
+            ????.######..#####. 1,6,5
+            ?###???????? 3,2,1";
+        assert_eq!(solve(input), Ok(21));
+
+        let row: Row = ".# 1".parse().unwrap();
+        assert_eq!(row.unfold(3).to_string(), ".#?.#?.# 1,1,1");
+        let (_, stats) = row.count();
+        assert_eq!(
+            stats,
+            CacheStats {
+                states: 6,
+                reachable: 3
+            }
+        );
+        assert_eq!("#.? 1,0".parse::<Row>().unwrap_err().message, "empty group");
+    }
+
+    /// Whether `arrangement` fits the known springs and has the row's groups.
+    fn is_arrangement(row: &Row, arrangement: &str) -> bool {
+        let fits = arrangement.len() == row.springs.len()
+            && row
+                .springs
+                .iter()
+                .zip(arrangement.chars())
+                .all(|(s, c)| *s == Spring::Unknown || s.symbol() == c);
+        let groups: Vec<_> = arrangement
+            .split('.')
+            .filter(|run| !run.is_empty())
+            .map(str::len)
+            .collect();
+        fits && groups == row.groups
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let row: Row = "?###???????? 3,2,1".parse().unwrap();
+        let all: Vec<_> = row.arrangements().collect();
+        assert_eq!(all.len(), 10);
+        assert_eq!(all[0], ".###.##.#...");
+        assert!(all.iter().all(|a| is_arrangement(&row, a)));
+        let mut distinct = all.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), all.len());
+        assert_eq!(row.arrangements().take(3).count(), 3);
+
+        let row = row.unfold(2);
+        let sampler = row.sampler();
+        assert_eq!(sampler.count(), 150);
+        let mut rng = fastrand::Rng::with_seed(12);
+        let mut seen = Vec::new();
+        for _ in 0..2000 {
+            let sample = sampler.sample(&mut rng).unwrap();
+            assert!(is_arrangement(&row, &sample));
+            seen.push(sample);
+        }
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 150);
+
+        let row: Row = "#.# 3".parse().unwrap();
+        assert_eq!(row.arrangements().next(), None);
+        assert_eq!(row.sampler().sample(&mut rng), None);
+    }
+}
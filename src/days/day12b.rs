@@ -0,0 +1,26 @@
+use super::day12a::count_all;
+use crate::parse::ParseError;
+
+/// How many copies of each row part b works on.
+pub const UNFOLD: usize = 5;
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    count_all(input, UNFOLD).map(|(total, _)| total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            ???.### 1,1,3
+            .??..??...?##. 1,1,3
+            ?#?#?#?#?#?#?#? 1,3,1,6
+            ????.#...#... 4,1,1
+            ????.######..#####. 1,6,5
+            ?###???????? 3,2,1";
+        assert_eq!(solve(input), Ok(525152));
+    }
+}
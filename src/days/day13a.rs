@@ -0,0 +1,104 @@
+use std::cmp::min;
+
+use crate::{grid::Grid, parse::ParseError};
+
+enum Reflection {
+    Vertical(usize),   // Reflection between columns with n columns to the left
+    Horizontal(usize), // Reflection between rows with n rows above
+}
+
+fn solve_puzzle(puzzle: &str) -> Result<Reflection, ParseError> {
+    let grid = Grid::try_parse(puzzle, |c| match c {
+        '#' | '.' => Ok(c),
+        _ => Err(ParseError::invalid_char(c, "tile")),
+    })?;
+    let rows: Vec<&[char]> = grid.rows().collect();
+    for (i, j) in (0..rows.len())
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| (w[0], w[1]))
+    {
+        let rows_to_cmp = min(i + 1, rows.len() - j);
+        let mut matches = true;
+        for k in 0..rows_to_cmp {
+            let row_a = &rows[i - k];
+            let row_b = &rows[j + k];
+            if row_a != row_b {
+                matches = false;
+                break;
+            }
+        }
+        if matches {
+            return Ok(Reflection::Horizontal(j));
+        }
+    }
+
+    let transposed = grid.transpose();
+    let cols: Vec<&[char]> = transposed.rows().collect();
+    for (i, j) in (0..cols.len())
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| (w[0], w[1]))
+    {
+        let cols_to_cmp = min(i + 1, cols.len() - j);
+        let mut matches = true;
+        for k in 0..cols_to_cmp {
+            let col_a = &cols[i - k];
+            let col_b = &cols[j + k];
+            if col_a != col_b {
+                matches = false;
+                break;
+            }
+        }
+        if matches {
+            return Ok(Reflection::Vertical(j));
+        }
+    }
+
+    Err(ParseError::new(
+        &puzzle[..0],
+        "no line of reflection in pattern",
+    ))
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let puzzles: Vec<_> = input.trim().split("\n\n").collect();
+    let reflections = puzzles
+        .into_iter()
+        .map(solve_puzzle)
+        .collect::<Result<Vec<_>, _>>()?;
+    let ans: usize = reflections
+        .into_iter()
+        .map(|r| match r {
+            Reflection::Vertical(n) => n,
+            Reflection::Horizontal(n) => n * 100,
+        })
+        .sum();
+    Ok(ans as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            #.##..##.
+            ..#.##.#.
+            ##......#
+            ##......#
+            ..#.##.#.
+            ..##..##.
+            #.#.##.#.
+
+            #...##..#
+            #....#..#
+            ..##..###
+            #####.##.
+            #####.##.
+            ..##..###
+            #....#..#";
+        assert_eq!(solve(input), Ok(405));
+    }
+}
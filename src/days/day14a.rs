@@ -0,0 +1,106 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{grid::Grid, parse::ParseError};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    RoundRock,
+    SquareRock,
+    Empty,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::RoundRock => 'O',
+            Tile::SquareRock => '#',
+            Tile::Empty => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+fn calculate_load(tiles: &Grid<Tile>) -> u64 {
+    let mut load = 0;
+    for ((_, y), tile) in tiles.iter() {
+        if *tile == Tile::RoundRock {
+            load += tiles.height() as u64 - y as u64;
+        }
+    }
+    load
+}
+
+fn tilt_north(tiles: &mut Grid<Tile>) {
+    let mut moved = 1;
+    while moved > 0 {
+        moved = 0;
+        // Skip first row
+        for y in 1..tiles.height() {
+            for x in 0..tiles.width() {
+                let cur_tile = tiles[(x, y)];
+                let above_tile = tiles[(x, y - 1)];
+                if cur_tile == Tile::RoundRock && above_tile == Tile::Empty {
+                    tiles[(x, y)] = Tile::Empty;
+                    tiles[(x, y - 1)] = Tile::RoundRock;
+                    moved += 1;
+                }
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::try_parse(input, |c| match c {
+        '#' => Ok(Tile::SquareRock),
+        'O' => Ok(Tile::RoundRock),
+        '.' => Ok(Tile::Empty),
+        _ => Err(ParseError::invalid_char(c, "tile")),
+    })
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let mut tiles = parse_input(input)?;
+    tilt_north(&mut tiles);
+
+    Ok(calculate_load(&tiles))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            O....#....
+            O.OO#....#
+            .....##...
+            OO.#O....O
+            .O.....O#.
+            O.#..O.#.#
+            ..O..#O..O
+            .......O..
+            #....###..
+            #OO..#....";
+        let mut tiles = parse_input(input).unwrap();
+        tilt_north(&mut tiles);
+
+        let tilted_input = "
+            OOOO.#.O..
+            OO..#....#
+            OO..O##..O
+            O..#.OO...
+            ........#.
+            ..#....#.#
+            ..O..#.O.O
+            ..O.......
+            #....###..
+            #....#....";
+        let tilted_tiles = parse_input(tilted_input).unwrap();
+        assert_eq!(tiles, tilted_tiles);
+
+        assert_eq!(calculate_load(&tiles), 136);
+
+        assert_eq!(solve(input), Ok(136));
+    }
+}
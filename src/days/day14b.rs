@@ -0,0 +1,321 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use crate::{cycle, geometry::Direction, grid::Grid, parse::ParseError};
+
+/// The widest and tallest platform a bitboard holds.
+const MAX_SIZE: usize = u128::BITS as usize;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum Tile {
+    RoundRock,
+    SquareRock,
+    Empty,
+}
+
+type Platform = Grid<Tile>;
+
+fn calculate_load(platform: &Platform) -> u64 {
+    let mut load = 0;
+    for ((_, y), tile) in platform.iter() {
+        if *tile == Tile::RoundRock {
+            load += platform.height() as u64 - y as u64;
+        }
+    }
+    load
+}
+
+fn tilt(tiles: &mut Platform, dir: Direction) {
+    let mut moved = 1;
+    while moved > 0 {
+        moved = 0;
+        for pos in tiles.positions() {
+            if tiles[pos] != Tile::RoundRock {
+                continue;
+            }
+            let adjacent_pos = match tiles.step(pos, dir) {
+                Some(adjacent_pos) => adjacent_pos,
+                None => continue,
+            };
+            if tiles[adjacent_pos] == Tile::Empty {
+                tiles[pos] = Tile::Empty;
+                tiles[adjacent_pos] = Tile::RoundRock;
+                moved += 1;
+            }
+        }
+    }
+}
+
+/// Tilts the platform north, west, south and east in turn.
+fn tilt_cycle(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+    tilt(&mut platform, Direction::North);
+    tilt(&mut platform, Direction::West);
+    tilt(&mut platform, Direction::South);
+    tilt(&mut platform, Direction::East);
+    platform
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::RoundRock => 'O',
+            Tile::SquareRock => '#',
+            Tile::Empty => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// A run of cells in a row or column between square rocks, where round
+/// rocks pile up at one end or the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    mask: u128,
+    start: u32,
+    end: u32,
+}
+
+/// The square rocks, which never move, as the segments of every row and
+/// column that round rocks settle in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    width: usize,
+    height: usize,
+    row_segments: Vec<Vec<Segment>>,
+    column_segments: Vec<Vec<Segment>>,
+}
+
+/// Where the round rocks are, one bitboard per row with bit `x` set for a
+/// rock in column `x`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rocks {
+    rows: Vec<u128>,
+}
+
+/// A run of `n` set bits.
+fn ones(n: u32) -> u128 {
+    u128::MAX.checked_shr(u128::BITS - n).unwrap_or(0)
+}
+
+/// The segments between the set bits of `squares` in a line of `len` cells.
+fn segments(squares: u128, len: usize) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for end in (0..=len as u32).filter(|&i| i == len as u32 || squares >> i & 1 == 1) {
+        if start < end {
+            let mask = ones(end - start) << start;
+            segments.push(Segment { mask, start, end });
+        }
+        start = end + 1;
+    }
+    segments
+}
+
+/// Swaps rows and columns, giving `len` lines.
+fn transpose(lines: &[u128], len: usize) -> Vec<u128> {
+    let mut transposed = vec![0; len];
+    for (i, &line) in lines.iter().enumerate() {
+        let mut bits = line;
+        while bits != 0 {
+            transposed[bits.trailing_zeros() as usize] |= 1 << i;
+            bits &= bits - 1;
+        }
+    }
+    transposed
+}
+
+/// Slides the rocks in each line to the low or high end of their segments,
+/// by counting how many are in each.
+fn tilt_lines(lines: &[u128], segments: &[Vec<Segment>], to_low: bool) -> Vec<u128> {
+    lines
+        .iter()
+        .zip(segments)
+        .map(|(&line, segments)| {
+            segments
+                .iter()
+                .map(|segment| {
+                    let rocks = (line & segment.mask).count_ones();
+                    let at = if to_low {
+                        segment.start
+                    } else {
+                        segment.end - rocks
+                    };
+                    ones(rocks).checked_shl(at).unwrap_or(0)
+                })
+                .fold(0, |line, rocks| line | rocks)
+        })
+        .collect()
+}
+
+impl Layout {
+    /// Tilts the platform north, west, south and east in turn. North and
+    /// south tilts work on columns, so the rocks are transposed around them.
+    pub fn tilt_cycle(&self, rocks: &Rocks) -> Rocks {
+        let columns = transpose(&rocks.rows, self.width);
+        let columns = tilt_lines(&columns, &self.column_segments, true);
+        let rows = transpose(&columns, self.height);
+        let rows = tilt_lines(&rows, &self.row_segments, true);
+        let columns = transpose(&rows, self.width);
+        let columns = tilt_lines(&columns, &self.column_segments, false);
+        let rows = transpose(&columns, self.height);
+        Rocks {
+            rows: tilt_lines(&rows, &self.row_segments, false),
+        }
+    }
+
+    pub fn load(&self, rocks: &Rocks) -> u64 {
+        rocks
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(y, row)| (self.height - y) as u64 * row.count_ones() as u64)
+            .sum()
+    }
+}
+
+impl Rocks {
+    /// A 64-bit hash of the rocks, to keep in place of the whole board.
+    pub fn key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Platform, ParseError> {
+    Grid::try_parse(input, |c| match c {
+        '#' => Ok(Tile::SquareRock),
+        'O' => Ok(Tile::RoundRock),
+        '.' => Ok(Tile::Empty),
+        _ => Err(ParseError::invalid_char(c, "tile")),
+    })
+}
+
+/// Packs the platform into bitboards.
+pub fn parse_bitboards(input: &str) -> Result<(Layout, Rocks), ParseError> {
+    let platform = parse_input(input)?;
+    let (width, height) = (platform.width(), platform.height());
+    if width > MAX_SIZE || height > MAX_SIZE {
+        let first = input.trim().lines().next().unwrap_or_default();
+        return Err(ParseError::new(first, "platform is larger than 128 by 128"));
+    }
+    let bits = |tile: Tile, cells: &mut dyn Iterator<Item = &Tile>| {
+        cells
+            .enumerate()
+            .filter(|&(_, &t)| t == tile)
+            .fold(0, |bits, (i, _)| bits | 1 << i)
+    };
+    let layout = Layout {
+        width,
+        height,
+        row_segments: platform
+            .rows()
+            .map(|row| segments(bits(Tile::SquareRock, &mut row.iter()), width))
+            .collect(),
+        column_segments: platform
+            .columns()
+            .map(|mut column| segments(bits(Tile::SquareRock, &mut column), height))
+            .collect(),
+    };
+    let rocks = Rocks {
+        rows: platform
+            .rows()
+            .map(|row| bits(Tile::RoundRock, &mut row.iter()))
+            .collect(),
+    };
+    Ok((layout, rocks))
+}
+
+/// The load after `cycles` spins, moving the rocks one cell at a time on a
+/// grid. Kept to compare `solve` against.
+pub fn solve_grid(input: &str, cycles: usize) -> Result<u64, ParseError> {
+    let platform = parse_input(input)?;
+    let history = cycle::find_cycle(platform, tilt_cycle);
+    Ok(calculate_load(history.state_at(cycles)))
+}
+
+pub fn solve(input: &str, cycles: usize) -> Result<u64, ParseError> {
+    let (layout, rocks) = parse_bitboards(input)?;
+    let history = cycle::find_cycle_by_key(rocks, |rocks| layout.tilt_cycle(rocks), Rocks::key);
+    Ok(layout.load(history.state_at(cycles)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            O....#....
+            O.OO#....#
+            .....##...
+            OO.#O....O
+            .O.....O#.
+            O.#..O.#.#
+            ..O..#O..O
+            .......O..
+            #....###..
+            #OO..#....";
+        let platform = parse_input(input).unwrap();
+
+        let one_cycle = "
+            .....#....
+            ....#...O#
+            ...OO##...
+            .OO#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#....
+            ......OOOO
+            #...O###..
+            #..OO#....";
+        let one_cycle_platform = parse_input(one_cycle).unwrap();
+        let platform = tilt_cycle(&platform);
+        assert_eq!(platform, one_cycle_platform);
+
+        let two_cycles = "
+            .....#....
+            ....#...O#
+            .....##...
+            ..O#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#...O
+            .......OOO
+            #..OO###..
+            #.OOO#...O";
+        let two_cycles_platform = parse_input(two_cycles).unwrap();
+        let platform = tilt_cycle(&platform);
+        assert_eq!(platform, two_cycles_platform);
+
+        let three_cycles = "
+            .....#....
+            ....#...O#
+            .....##...
+            ..O#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#...O
+            .......OOO
+            #...O###.O
+            #.OOO#...O";
+        let three_cycles_platform = parse_input(three_cycles).unwrap();
+        let platform = tilt_cycle(&platform);
+        assert_eq!(platform, three_cycles_platform);
+
+        let (layout, mut rocks) = parse_bitboards(input).unwrap();
+        for expected in [one_cycle, two_cycles, three_cycles] {
+            rocks = layout.tilt_cycle(&rocks);
+            assert_eq!(rocks, parse_bitboards(expected).unwrap().1);
+        }
+        assert_eq!(layout.load(&rocks), calculate_load(&platform));
+        assert_eq!(solve_grid(input, 1000000000), Ok(64));
+
+        assert_eq!(solve(input, 1000000000), Ok(64));
+    }
+}
@@ -0,0 +1,28 @@
+use crate::parse::ParseError;
+
+fn hash(s: &str) -> u64 {
+    let mut cur = 0;
+    for c in s.chars() {
+        cur += c as u64;
+        cur *= 17;
+        cur %= 256;
+    }
+    cur
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    Ok(input.trim().split(',').map(hash).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(hash("HASH"), 52);
+
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(solve(input), Ok(1320));
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::{
+    geometry::Direction,
+    grid::{Grid, Pos},
+    parse::ParseError,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tile {
+    Empty,
+    MirrorForward,
+    MirrorBackward,
+    SplitterVertical,
+    SplitterHorizontal,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Result<Tile, ParseError> {
+        match c {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::MirrorForward),
+            '\\' => Ok(Tile::MirrorBackward),
+            '|' => Ok(Tile::SplitterVertical),
+            '-' => Ok(Tile::SplitterHorizontal),
+            _ => Err(ParseError::invalid_char(c, "tile")),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Tile::Empty => ".",
+            Tile::MirrorForward => "/",
+            Tile::MirrorBackward => "\\",
+            Tile::SplitterVertical => "|",
+            Tile::SplitterHorizontal => "-",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+fn get_next_directions(cur_dir: &Direction, tile: &Tile) -> Vec<Direction> {
+    match tile {
+        Tile::Empty => vec![*cur_dir],
+        Tile::MirrorForward => match cur_dir {
+            Direction::North => vec![Direction::East],
+            Direction::South => vec![Direction::West],
+            Direction::West => vec![Direction::South],
+            Direction::East => vec![Direction::North],
+        },
+        Tile::MirrorBackward => match cur_dir {
+            Direction::North => vec![Direction::West],
+            Direction::South => vec![Direction::East],
+            Direction::West => vec![Direction::North],
+            Direction::East => vec![Direction::South],
+        },
+        Tile::SplitterVertical => match cur_dir {
+            Direction::North => vec![Direction::North],
+            Direction::South => vec![Direction::South],
+            Direction::West => vec![Direction::North, Direction::South],
+            Direction::East => vec![Direction::North, Direction::South],
+        },
+        Tile::SplitterHorizontal => match cur_dir {
+            Direction::North => vec![Direction::West, Direction::East],
+            Direction::South => vec![Direction::West, Direction::East],
+            Direction::West => vec![Direction::West],
+            Direction::East => vec![Direction::East],
+        },
+    }
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let grid = Grid::try_parse(input, Tile::from_char)?;
+    if grid.width() == 0 {
+        return Err(ParseError::missing(input, "contraption"));
+    }
+
+    let mut visited: HashSet<(Pos, Direction)> = HashSet::new();
+    let mut to_visit: Vec<(Pos, Direction)> = vec![((0, 0), Direction::East)];
+    while let Some((pos, dir)) = to_visit.pop() {
+        if visited.contains(&(pos, dir)) {
+            continue;
+        }
+        visited.insert((pos, dir));
+        let tile = grid[pos];
+        let next_dirs = get_next_directions(&dir, &tile);
+        for next_dir in next_dirs {
+            if let Some(next_pos) = grid.step(pos, next_dir) {
+                to_visit.push((next_pos, next_dir));
+            }
+        }
+    }
+    let visited: HashSet<Pos> = visited.iter().map(|(pos, _)| *pos).collect();
+    Ok(visited.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = r"
+            .|...\....
+            |.-.\.....
+            .....|-...
+            ........|.
+            ..........
+            .........\
+            ..../.\\..
+            .-.-/..|..
+            .|....-|.\
+            ..//.|....";
+        assert_eq!(solve(input), Ok(46));
+        assert_eq!(solve("").unwrap_err().to_string(), "missing contraption");
+    }
+}
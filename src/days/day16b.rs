@@ -0,0 +1,128 @@
+use rustc_hash::FxHashSet;
+
+use crate::{
+    geometry::Direction,
+    grid::{Grid, Pos},
+    parse::ParseError,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tile {
+    Empty,
+    MirrorForward,
+    MirrorBackward,
+    SplitterVertical,
+    SplitterHorizontal,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Result<Tile, ParseError> {
+        match c {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::MirrorForward),
+            '\\' => Ok(Tile::MirrorBackward),
+            '|' => Ok(Tile::SplitterVertical),
+            '-' => Ok(Tile::SplitterHorizontal),
+            _ => Err(ParseError::invalid_char(c, "tile")),
+        }
+    }
+}
+
+fn get_next_directions(cur_dir: &Direction, tile: &Tile) -> Vec<Direction> {
+    match tile {
+        Tile::Empty => vec![*cur_dir],
+        Tile::MirrorForward => match cur_dir {
+            Direction::North => vec![Direction::East],
+            Direction::South => vec![Direction::West],
+            Direction::West => vec![Direction::South],
+            Direction::East => vec![Direction::North],
+        },
+        Tile::MirrorBackward => match cur_dir {
+            Direction::North => vec![Direction::West],
+            Direction::South => vec![Direction::East],
+            Direction::West => vec![Direction::North],
+            Direction::East => vec![Direction::South],
+        },
+        Tile::SplitterVertical => match cur_dir {
+            Direction::North => vec![Direction::North],
+            Direction::South => vec![Direction::South],
+            Direction::West => vec![Direction::North, Direction::South],
+            Direction::East => vec![Direction::North, Direction::South],
+        },
+        Tile::SplitterHorizontal => match cur_dir {
+            Direction::North => vec![Direction::West, Direction::East],
+            Direction::South => vec![Direction::West, Direction::East],
+            Direction::West => vec![Direction::West],
+            Direction::East => vec![Direction::East],
+        },
+    }
+}
+
+fn get_energized(grid: &Grid<Tile>, initial: (Pos, Direction)) -> u64 {
+    let mut visited: FxHashSet<(Pos, Direction)> = FxHashSet::default();
+    let mut to_visit: Vec<(Pos, Direction)> = vec![initial];
+    while let Some((pos, dir)) = to_visit.pop() {
+        if visited.contains(&(pos, dir)) {
+            continue;
+        }
+        visited.insert((pos, dir));
+        let tile = grid[pos];
+        let next_dirs = get_next_directions(&dir, &tile);
+        for next_dir in next_dirs {
+            if let Some(next_pos) = grid.step(pos, next_dir) {
+                to_visit.push((next_pos, next_dir));
+            }
+        }
+    }
+    let visited: FxHashSet<Pos> = visited.iter().map(|(pos, _)| *pos).collect();
+    visited.len() as u64
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let grid = Grid::try_parse(input, Tile::from_char)?;
+    let width = grid.width();
+    let height = grid.height();
+    let mut max = 0;
+    for y in 0..height {
+        let energized = get_energized(&grid, ((0, y), Direction::East));
+        if energized > max {
+            max = energized;
+        }
+        let energized = get_energized(&grid, ((width - 1, y), Direction::West));
+        if energized > max {
+            max = energized;
+        }
+    }
+    for x in 0..width {
+        let energized = get_energized(&grid, ((x, 0), Direction::South));
+        if energized > max {
+            max = energized;
+        }
+        let energized = get_energized(&grid, ((x, height - 1), Direction::North));
+        if energized > max {
+            max = energized;
+        }
+    }
+    Ok(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = r"
+            .|...\....
+            |.-.\.....
+            .....|-...
+            ........|.
+            ..........
+            .........\
+            ..../.\\..
+            .-.-/..|..
+            .|....-|.\
+            ..//.|....";
+        assert_eq!(solve(input), Ok(51));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    geometry::Direction,
+    grid::{Grid, Pos},
+    parse::ParseError,
+    search::{astar, Path},
+};
+
+/// How far a crucible may travel in a straight line before it must turn,
+/// and how far it must travel before it may turn (or stop).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Rules {
+    pub min_run: usize,
+    pub max_run: usize,
+}
+
+const RULES: Rules = Rules {
+    min_run: 0,
+    max_run: 3,
+};
+
+/// A crucible on the map: where it is, which way it is facing and how many
+/// blocks it has moved in that direction since its last turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Crucible {
+    pos: Pos,
+    dir: Direction,
+    run: usize,
+}
+
+impl Crucible {
+    fn successors(&self, grid: &Grid<u8>, rules: Rules) -> Vec<(Crucible, u64)> {
+        let mut moves = Vec::with_capacity(3);
+        if self.run < rules.max_run {
+            moves.push((self.dir, self.run + 1));
+        }
+        if self.run >= rules.min_run {
+            moves.push((self.dir.turn_left(), 1));
+            moves.push((self.dir.turn_right(), 1));
+        }
+        moves
+            .into_iter()
+            .filter_map(|(dir, run)| {
+                let pos = grid.step(self.pos, dir)?;
+                Some((Crucible { pos, dir, run }, grid[pos] as u64))
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::try_parse(input, |c| match c.to_digit(10) {
+        Some(heat) => Ok(heat as u8),
+        None => Err(ParseError::invalid_char(c, "heat loss")),
+    })
+}
+
+/// Finds the route from the top-left to the bottom-right block that loses
+/// the least heat, or `None` if the rules make the factory unreachable.
+fn min_heat_loss(grid: &Grid<u8>, rules: Rules) -> Option<Path<Crucible>> {
+    let end = (grid.width() - 1, grid.height() - 1);
+    // The first block is never entered, so the crucible may set off either way.
+    let starts = [Direction::East, Direction::South].map(|dir| Crucible {
+        pos: (0, 0),
+        dir,
+        run: 0,
+    });
+    astar(
+        starts,
+        |crucible| crucible.successors(grid, rules),
+        // Every block costs at least 1, so the Manhattan distance is a lower bound.
+        |crucible| ((end.0 - crucible.pos.0) + (end.1 - crucible.pos.1)) as u64,
+        |crucible| crucible.pos == end && crucible.run >= rules.min_run,
+    )
+}
+
+/// The cheapest route for a crucible and the map it runs over.
+pub struct Route {
+    grid: Grid<u8>,
+    path: Path<Crucible>,
+}
+
+impl Route {
+    pub fn heat_loss(&self) -> u64 {
+        self.path.cost
+    }
+
+    /// Draws the map with the direction of travel over every block the route
+    /// enters.
+    pub fn render(&self) -> String {
+        let mut chars = self.grid.map(|&heat| char::from(b'0' + heat));
+        for crucible in self.path.states.iter().skip(1) {
+            chars[crucible.pos] = match crucible.dir {
+                Direction::North => '^',
+                Direction::East => '>',
+                Direction::South => 'v',
+                Direction::West => '<',
+            };
+        }
+        chars.to_string()
+    }
+}
+
+/// The cheapest route for a crucible under `rules`.
+pub(crate) fn find_route(input: &str, rules: Rules) -> Result<Route, ParseError> {
+    let grid = parse_input(input)?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(ParseError::missing(input, "map"));
+    }
+    match min_heat_loss(&grid, rules) {
+        Some(path) => Ok(Route { grid, path }),
+        None => Err(ParseError::unsolvable("no route reaches the factory")),
+    }
+}
+
+/// The cheapest route for a crucible.
+pub fn route(input: &str) -> Result<Route, ParseError> {
+    find_route(input, RULES)
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    route(input).map(|route| route.heat_loss())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            2413432311323
+            3215453535623
+            3255245654254
+            3446585845452
+            4546657867536
+            1438598798454
+            4457876987766
+            3637877979653
+            4654967986887
+            4564679986453
+            1224686865563
+            2546548887735
+            4322674655533";
+        assert_eq!(solve(input), Ok(102));
+
+        let route = route(input).unwrap();
+        let entered: u64 = route.path.states[1..]
+            .iter()
+            .map(|c| route.grid[c.pos] as u64)
+            .sum();
+        assert_eq!(entered, route.heat_loss());
+        assert!(route.path.states.iter().all(|c| c.run <= 3));
+        let overlay = route.render();
+        assert_eq!(overlay.lines().count(), 13);
+        assert!(overlay.starts_with('2'));
+        assert!(overlay.trim_end().ends_with(['>', 'v']));
+
+        assert_eq!(solve("7"), Ok(0));
+        assert_eq!(solve("").unwrap_err().message, "missing map");
+    }
+}
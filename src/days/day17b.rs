@@ -0,0 +1,54 @@
+use super::day17a::{find_route, Route, Rules};
+use crate::parse::ParseError;
+
+const RULES: Rules = Rules {
+    min_run: 4,
+    max_run: 10,
+};
+
+/// The cheapest route for an ultra crucible.
+pub fn route(input: &str) -> Result<Route, ParseError> {
+    find_route(input, RULES)
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    route(input).map(|route| route.heat_loss())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ErrorKind;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            2413432311323
+            3215453535623
+            3255245654254
+            3446585845452
+            4546657867536
+            1438598798454
+            4457876987766
+            3637877979653
+            4654967986887
+            4564679986453
+            1224686865563
+            2546548887735
+            4322674655533";
+        assert_eq!(solve(input), Ok(94));
+
+        let input = "
+            111111111111
+            999999999991
+            999999999991
+            999999999991
+            999999999991";
+        assert_eq!(solve(input), Ok(71));
+
+        // An ultra crucible can't stop after fewer than four blocks.
+        let err = solve("12\n34").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Unsolvable);
+        assert_eq!(err.to_string(), "no solution: no route reaches the factory");
+    }
+}
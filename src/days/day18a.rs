@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use crate::{
+    geometry::{Direction, Point2},
+    parse::{self, ParseError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Instruction<'a> {
+    pub(crate) direction: Direction,
+    pub(crate) distance: u64,
+    /// The hex digits between `(#` and `)`.
+    color: &'a str,
+}
+
+impl<'a> Instruction<'a> {
+    pub(crate) fn from_string(s: &'a str) -> Result<Instruction<'a>, ParseError> {
+        let mut parts = s.split_whitespace();
+        let direction = parse::next(&mut parts, s, "direction")?.parse()?;
+        let distance = parse::number(parse::next(&mut parts, s, "distance")?)?;
+        let color = parse::next(&mut parts, s, "color")?;
+        let color = color
+            .strip_prefix("(#")
+            .and_then(|color| color.strip_suffix(')'))
+            .ok_or_else(|| ParseError::new(color, "expected '(#rrggbb)', found"))?;
+        Ok(Instruction {
+            direction,
+            distance,
+            color,
+        })
+    }
+
+    /// Reads the instruction hidden in the colour: five hex digits of
+    /// distance followed by one digit of direction (`0` right, then clockwise).
+    pub(crate) fn decode_color(&self) -> Result<Instruction<'a>, ParseError> {
+        let hex = self.color;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(ParseError::new(hex, "expected six hex digits, found"));
+        }
+        let distance = u64::from_str_radix(&hex[..5], 16)
+            .map_err(|_| ParseError::new(&hex[..5], "invalid hex distance"))?;
+        let direction = match &hex[5..] {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            other => return Err(ParseError::new(other, "invalid direction digit")),
+        };
+        Ok(Instruction {
+            direction,
+            distance,
+            color: hex,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TrenchType {
+    TopLeftCorner,
+    TopRightCorner,
+    BottomLeftCorner,
+    BottomRightCorner,
+    VerticalEdge,
+    HorizontalEdge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ClassifiedPoint {
+    Trench(TrenchType),
+    Inside,
+    Outside,
+    Unclassified,
+}
+
+fn classify_trench(trench: &HashSet<Point2>, p: Point2) -> Result<TrenchType, ParseError> {
+    let above = p.step(Direction::North);
+    let below = p.step(Direction::South);
+    let left = p.step(Direction::West);
+    let right = p.step(Direction::East);
+    Ok(if trench.contains(&below) && trench.contains(&right) {
+        TrenchType::TopLeftCorner
+    } else if trench.contains(&below) && trench.contains(&left) {
+        TrenchType::TopRightCorner
+    } else if trench.contains(&above) && trench.contains(&right) {
+        TrenchType::BottomLeftCorner
+    } else if trench.contains(&above) && trench.contains(&left) {
+        TrenchType::BottomRightCorner
+    } else if trench.contains(&above) && trench.contains(&below) {
+        TrenchType::VerticalEdge
+    } else if trench.contains(&left) && trench.contains(&right) {
+        TrenchType::HorizontalEdge
+    } else {
+        let message = format!("trench ends at {} without closing the loop", p);
+        return Err(ParseError::unsolvable(message));
+    })
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let instrs = parse::lines(input, Instruction::from_string)?;
+    if instrs.is_empty() {
+        return Err(ParseError::missing(input, "dig plan"));
+    }
+    let mut trench: HashSet<Point2> = HashSet::new();
+    let mut cur = Point2::ORIGIN;
+    trench.insert(cur);
+    for instr in instrs {
+        for _ in 0..instr.distance {
+            cur = cur.step(instr.direction);
+            trench.insert(cur);
+        }
+    }
+    let min_x = trench.iter().map(|p| p.x).min().unwrap();
+    let max_x = trench.iter().map(|p| p.x).max().unwrap();
+    let min_y = trench.iter().map(|p| p.y).min().unwrap();
+    let max_y = trench.iter().map(|p| p.y).max().unwrap();
+
+    let mut lagoon: Vec<Vec<ClassifiedPoint>> = (min_y..=max_y)
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| {
+                    let p = Point2::new(x, y);
+                    if trench.contains(&p) {
+                        Ok(ClassifiedPoint::Trench(classify_trench(&trench, p)?))
+                    } else {
+                        Ok(ClassifiedPoint::Unclassified)
+                    }
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    for y in min_y..=max_y {
+        let mut count = 0;
+        for x in min_x..=max_x {
+            let p = Point2::new(x - min_x, y - min_y);
+            match lagoon[p.y as usize][p.x as usize] {
+                ClassifiedPoint::Trench(TrenchType::TopLeftCorner) => (),
+                ClassifiedPoint::Trench(TrenchType::TopRightCorner) => (),
+                ClassifiedPoint::Trench(TrenchType::BottomLeftCorner) => count += 1,
+                ClassifiedPoint::Trench(TrenchType::BottomRightCorner) => count += 1,
+                ClassifiedPoint::Trench(TrenchType::VerticalEdge) => count += 1,
+                ClassifiedPoint::Trench(TrenchType::HorizontalEdge) => (),
+                ClassifiedPoint::Unclassified => {
+                    if count % 2 == 1 {
+                        lagoon[p.y as usize][p.x as usize] = ClassifiedPoint::Inside;
+                    } else {
+                        lagoon[p.y as usize][p.x as usize] = ClassifiedPoint::Outside;
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let inside = lagoon
+        .iter()
+        .flatten()
+        .filter(|&&p| p == ClassifiedPoint::Inside)
+        .count();
+    let size = trench.len() + inside;
+
+    Ok(size as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = r"
+            R 6 (#70c710)
+            D 5 (#0dc571)
+            L 2 (#5713f0)
+            D 2 (#d2c081)
+            R 2 (#59c680)
+            D 2 (#411b91)
+            L 5 (#8ceee2)
+            U 2 (#caa173)
+            L 1 (#1b58a2)
+            U 2 (#caa171)
+            R 2 (#7807d2)
+            U 3 (#a77fa3)
+            L 2 (#015232)
+            U 2 (#7a21e3)";
+        assert_eq!(solve(input), Ok(62));
+
+        assert_eq!(solve("").unwrap_err().to_string(), "missing dig plan");
+        let err = solve("R 2 (#000000)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no solution: trench ends at (0, 0) without closing the loop"
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use super::day18a::Instruction;
+use crate::{
+    parse::{self, ParseError},
+    polygon::Polygon,
+};
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let instrs = parse::lines(input, |line| Instruction::from_string(line)?.decode_color())?;
+    let last = input.lines().map(str::trim).rfind(|line| !line.is_empty());
+    let last = last.ok_or_else(|| ParseError::missing(input, "dig plan"))?;
+    let lagoon = Polygon::from_steps(instrs.iter().map(|i| (i.direction, i.distance as i64)));
+    let lagoon =
+        lagoon.ok_or_else(|| ParseError::new(last, "trench does not return to its start after"))?;
+    Ok(lagoon.enclosed_points() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction;
+
+    #[test]
+    fn test_solve() {
+        let input = r"
+            R 6 (#70c710)
+            D 5 (#0dc571)
+            L 2 (#5713f0)
+            D 2 (#d2c081)
+            R 2 (#59c680)
+            D 2 (#411b91)
+            L 5 (#8ceee2)
+            U 2 (#caa173)
+            L 1 (#1b58a2)
+            U 2 (#caa171)
+            R 2 (#7807d2)
+            U 3 (#a77fa3)
+            L 2 (#015232)
+            U 2 (#7a21e3)";
+        let decoded = Instruction::from_string("R 6 (#70c710)")
+            .and_then(|instr| instr.decode_color())
+            .unwrap();
+        assert_eq!(
+            (decoded.direction, decoded.distance),
+            (Direction::East, 461937)
+        );
+        let err = Instruction::from_string("R 6 (#70c714)")
+            .and_then(|instr| instr.decode_color())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid direction digit '4'");
+        assert_eq!(solve(input), Ok(952408144115));
+
+        assert_eq!(solve("").unwrap_err().to_string(), "missing dig plan");
+        let err = solve("R 6 (#000020)\nD 5 (#000021)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "trench does not return to its start after 'D 5 (#000021)'"
+        );
+    }
+}
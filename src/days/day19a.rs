@@ -0,0 +1,530 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{
+    interval::HyperRect,
+    parse::{self, ParseError},
+};
+
+lazy_static! {
+    static ref PART_REGEX: Regex =
+        Regex::new(r"^\{x=([0-9]+),m=([0-9]+),a=([0-9]+),s=([0-9]+)}$").unwrap();
+    static ref CONDITIONAL_RULE_REGEX: Regex =
+        Regex::new(r"^([xmas])([<>])([0-9]+):([a-zA-Z]+)$").unwrap();
+    static ref TARGET_REGEX: Regex = Regex::new(r"^[a-zA-Z]+$").unwrap();
+}
+
+/// The rating categories, in the order they are indexed everywhere else.
+pub(crate) const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+/// Every combination of ratings within some bounds, one axis per category.
+pub(crate) type PartRange = HyperRect<4>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Part {
+    pub ratings: [u64; 4],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Condition {
+    LessThan,
+    GreaterThan,
+}
+
+/// A comparison of one of a part's ratings against a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Test {
+    pub category: usize,
+    pub condition: Condition,
+    pub value: u64,
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    /// `None` for the unconditional rule that ends every workflow.
+    test: Option<Test>,
+    target: &'a str,
+}
+
+#[derive(Debug)]
+struct Workflow<'a> {
+    name: &'a str,
+    rules: Vec<Rule<'a>>,
+}
+
+/// A step of the compiled workflows: a verdict, a test that picks which node
+/// to go to next, or the single fallback rule of a workflow with no tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Node {
+    Accept,
+    Reject,
+    Test {
+        test: Test,
+        pass: usize,
+        fail: usize,
+        /// Index into `DecisionTree::workflows` of the workflow the test is from.
+        workflow: usize,
+    },
+    Forward {
+        to: usize,
+        workflow: usize,
+    },
+}
+
+/// Every workflow flattened into a single graph of tests, starting at the
+/// `in` workflow. Workflows sent to from several places are compiled once
+/// and shared, so this is a DAG rather than a tree.
+#[derive(Debug)]
+pub(crate) struct DecisionTree {
+    pub nodes: Vec<Node>,
+    pub root: usize,
+    pub workflows: Vec<String>,
+}
+
+/// A workflow a part passed through and the rule in it that sent it on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Step {
+    pub workflow: String,
+    pub rule: String,
+}
+
+/// The route a part took through the workflows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Trace {
+    pub steps: Vec<Step>,
+    pub accepted: bool,
+}
+
+const ACCEPT: usize = 0;
+const REJECT: usize = 1;
+
+impl FromStr for Part {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let caps = PART_REGEX
+            .captures(s)
+            .ok_or_else(|| ParseError::new(s, "invalid part"))?;
+        let mut ratings = [0; 4];
+        for (i, rating) in ratings.iter_mut().enumerate() {
+            *rating = parse::number(caps.get(i + 1).unwrap().as_str())?;
+        }
+        Ok(Part { ratings })
+    }
+}
+
+impl Part {
+    fn value(&self) -> u64 {
+        self.ratings.iter().sum()
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let ratings: Vec<_> = CATEGORIES
+            .iter()
+            .zip(self.ratings)
+            .map(|(c, rating)| format!("{}={}", c, rating))
+            .collect();
+        write!(f, "{{{}}}", ratings.join(","))
+    }
+}
+
+impl Display for Test {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let condition = match self.condition {
+            Condition::LessThan => '<',
+            Condition::GreaterThan => '>',
+        };
+        write!(
+            f,
+            "{}{}{}",
+            CATEGORIES[self.category], condition, self.value
+        )
+    }
+}
+
+impl Display for Trace {
+    /// The chain of workflows on one line, then the rule that fired in each.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            write!(f, "{} -> ", step.workflow)?;
+        }
+        write!(f, "{}", if self.accepted { 'A' } else { 'R' })?;
+        for step in &self.steps {
+            write!(f, "\n    {}: {}", step.workflow, step.rule)?;
+        }
+        Ok(())
+    }
+}
+
+impl Test {
+    pub fn matches(&self, part: &Part) -> bool {
+        let rating = part.ratings[self.category];
+        match self.condition {
+            Condition::LessThan => rating < self.value,
+            Condition::GreaterThan => rating > self.value,
+        }
+    }
+
+    /// Splits `range` into the parts that pass and fail the test.
+    pub fn split(&self, range: &PartRange) -> (Option<PartRange>, Option<PartRange>) {
+        match self.condition {
+            Condition::LessThan => range.split(self.category, self.value),
+            Condition::GreaterThan => {
+                let (fail, pass) = range.split(self.category, self.value + 1);
+                (pass, fail)
+            }
+        }
+    }
+}
+
+fn parse_rule(s: &str) -> Result<Rule<'_>, ParseError> {
+    if !s.contains(':') {
+        if !TARGET_REGEX.is_match(s) {
+            return Err(ParseError::new(s, "invalid rule"));
+        }
+        return Ok(Rule {
+            test: None,
+            target: s,
+        });
+    }
+    let caps = CONDITIONAL_RULE_REGEX
+        .captures(s)
+        .ok_or_else(|| ParseError::new(s, "invalid conditional rule"))?;
+    let category = CATEGORIES
+        .iter()
+        .position(|&c| caps[1].starts_with(c))
+        .unwrap();
+    let condition = match &caps[2] {
+        "<" => Condition::LessThan,
+        _ => Condition::GreaterThan,
+    };
+    let value = parse::number(caps.get(3).unwrap().as_str())?;
+    Ok(Rule {
+        test: Some(Test {
+            category,
+            condition,
+            value,
+        }),
+        target: caps.get(4).unwrap().as_str(),
+    })
+}
+
+fn parse_workflow(s: &str) -> Result<Workflow<'_>, ParseError> {
+    let (name, rules) = parse::split_once(s, "{")?;
+    let rules = rules
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::missing(s, "'}'"))?;
+    let last = rules.rsplit(',').next().unwrap();
+    let rules: Vec<_> = rules.split(',').map(parse_rule).collect::<Result<_, _>>()?;
+    if rules.last().is_some_and(|rule| rule.test.is_some()) {
+        return Err(ParseError::new(last, "no fallback rule in"));
+    }
+    Ok(Workflow { name, rules })
+}
+
+/// Turns workflows into nodes, compiling each one the first time it is sent
+/// to. A workflow that is still being compiled when it is reached again is
+/// part of a cycle.
+struct Compiler<'a> {
+    workflows: HashMap<&'a str, Vec<Rule<'a>>>,
+    /// Entry node of each workflow, or `None` while it is being compiled.
+    entries: HashMap<&'a str, Option<usize>>,
+    nodes: Vec<Node>,
+    names: Vec<String>,
+}
+
+impl<'a> Compiler<'a> {
+    fn resolve(&mut self, target: &'a str) -> Result<usize, ParseError> {
+        match target {
+            "A" => return Ok(ACCEPT),
+            "R" => return Ok(REJECT),
+            _ => (),
+        }
+        match self.entries.get(target) {
+            Some(Some(entry)) => return Ok(*entry),
+            Some(None) => return Err(ParseError::new(target, "workflow cycle through")),
+            None => (),
+        }
+        let rules = self
+            .workflows
+            .remove(target)
+            .ok_or_else(|| ParseError::new(target, "undefined workflow"))?;
+        self.entries.insert(target, None);
+        let workflow = self.names.len();
+        self.names.push(target.to_string());
+        // Build the chain back to front, so each test knows where failing
+        // parts go next.
+        let mut next = REJECT;
+        for rule in rules.iter().rev() {
+            let to = self.resolve(rule.target)?;
+            next = match rule.test {
+                None => to,
+                Some(test) => {
+                    self.nodes.push(Node::Test {
+                        test,
+                        pass: to,
+                        fail: next,
+                        workflow,
+                    });
+                    self.nodes.len() - 1
+                }
+            };
+        }
+        if rules.iter().all(|rule| rule.test.is_none()) {
+            // Nothing but a fallback, which still needs a node so that rules
+            // sending parts here name this workflow and not the next one.
+            self.nodes.push(Node::Forward { to: next, workflow });
+            next = self.nodes.len() - 1;
+        }
+        self.entries.insert(target, Some(next));
+        Ok(next)
+    }
+}
+
+impl DecisionTree {
+    /// Compiles every workflow, not just those reachable from `in`, so that
+    /// any undefined target or cycle in the input is reported.
+    fn compile<'a>(
+        section: &'a str,
+        workflows: Vec<Workflow<'a>>,
+    ) -> Result<DecisionTree, ParseError> {
+        let names: Vec<_> = workflows.iter().map(|w| w.name).collect();
+        let mut compiler = Compiler {
+            workflows: HashMap::new(),
+            entries: HashMap::new(),
+            nodes: vec![Node::Accept, Node::Reject],
+            names: Vec::new(),
+        };
+        for workflow in workflows {
+            if compiler.workflows.contains_key(workflow.name) {
+                return Err(ParseError::new(workflow.name, "duplicate workflow"));
+            }
+            compiler.workflows.insert(workflow.name, workflow.rules);
+        }
+        if !compiler.workflows.contains_key("in") {
+            return Err(ParseError::missing(section, "workflow 'in'"));
+        }
+        for name in names {
+            compiler.resolve(name)?;
+        }
+        Ok(DecisionTree {
+            root: compiler.entries["in"].unwrap(),
+            nodes: compiler.nodes,
+            workflows: compiler.names,
+        })
+    }
+
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut node = self.root;
+        loop {
+            match self.nodes[node] {
+                Node::Accept => return true,
+                Node::Reject => return false,
+                Node::Test {
+                    test, pass, fail, ..
+                } => {
+                    node = if test.matches(part) { pass } else { fail };
+                }
+                Node::Forward { to, .. } => node = to,
+            }
+        }
+    }
+
+    /// Where `node` leads, as written in a rule.
+    fn target_name(&self, node: usize) -> &str {
+        match self.nodes[node] {
+            Node::Accept => "A",
+            Node::Reject => "R",
+            Node::Test { workflow, .. } | Node::Forward { workflow, .. } => {
+                &self.workflows[workflow]
+            }
+        }
+    }
+
+    fn workflow_of(&self, node: usize) -> Option<usize> {
+        match self.nodes[node] {
+            Node::Test { workflow, .. } | Node::Forward { workflow, .. } => Some(workflow),
+            _ => None,
+        }
+    }
+
+    /// Runs `part` through the workflows like `accepts`, recording the rule
+    /// that fired in each one.
+    pub fn trace(&self, part: &Part) -> Trace {
+        let mut steps = Vec::new();
+        let mut node = self.root;
+        loop {
+            let (rule, workflow) = match self.nodes[node] {
+                Node::Accept | Node::Reject => break,
+                Node::Forward { to, workflow } => {
+                    node = to;
+                    (self.target_name(to).to_string(), workflow)
+                }
+                Node::Test {
+                    test,
+                    pass,
+                    fail,
+                    workflow,
+                } => {
+                    let passed = test.matches(part);
+                    node = if passed { pass } else { fail };
+                    if passed {
+                        (format!("{}:{}", test, self.target_name(pass)), workflow)
+                    } else if self.workflow_of(fail) != Some(workflow) {
+                        // Failed the workflow's last test, so its fallback fired.
+                        (self.target_name(fail).to_string(), workflow)
+                    } else {
+                        continue;
+                    }
+                }
+            };
+            let workflow = self.workflows[workflow].clone();
+            steps.push(Step { workflow, rule });
+        }
+        Trace {
+            steps,
+            accepted: self.nodes[node] == Node::Accept,
+        }
+    }
+
+    /// Splits `range` into the disjoint pieces that end up accepted.
+    pub fn accepted(&self, range: PartRange) -> Vec<PartRange> {
+        let mut accepted = Vec::new();
+        let mut stack = vec![(self.root, range)];
+        while let Some((node, range)) = stack.pop() {
+            match self.nodes[node] {
+                Node::Accept => accepted.push(range),
+                Node::Reject => (),
+                Node::Test {
+                    test, pass, fail, ..
+                } => {
+                    let (passed, failed) = test.split(&range);
+                    stack.extend(passed.map(|range| (pass, range)));
+                    stack.extend(failed.map(|range| (fail, range)));
+                }
+                Node::Forward { to, .. } => stack.push((to, range)),
+            }
+        }
+        accepted
+    }
+}
+
+/// Compiles the workflows and parses the parts listed after them, if any.
+pub(crate) fn parse_input(input: &str) -> Result<(DecisionTree, Vec<Part>), ParseError> {
+    let input = input.trim();
+    let (workflows, parts) = input.split_once("\n\n").unwrap_or((input, ""));
+    // Workflows borrow their names from the input, which `parse::lines`
+    // does not allow for.
+    let tree = DecisionTree::compile(
+        workflows,
+        workflows
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(parse_workflow)
+            .collect::<Result<_, _>>()?,
+    )?;
+    let parts = parse::lines(parts, str::parse)?;
+    Ok((tree, parts))
+}
+
+/// Explains the verdict on every part, one trace per part.
+pub fn explain(input: &str) -> Result<String, ParseError> {
+    let (tree, parts) = parse_input(input)?;
+    let traces: Vec<_> = parts
+        .iter()
+        .map(|part| format!("{}: {}", part, tree.trace(part)))
+        .collect();
+    Ok(traces.join("\n"))
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let (tree, parts) = parse_input(input)?;
+    Ok(parts
+        .iter()
+        .filter(|part| tree.accepts(part))
+        .map(Part::value)
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = r"
+            px{a<2006:qkq,m>2090:A,rfg}
+            pv{a>1716:R,A}
+            lnx{m>1548:A,A}
+            rfg{s<537:gd,x>2440:R,A}
+            qs{s>3448:A,lnx}
+            qkq{x<1416:A,crn}
+            crn{x>2662:A,R}
+            in{s<1351:px,qqz}
+            qqz{s>2770:qs,m<1801:hdj,R}
+            gd{a>3333:R,R}
+            hdj{m>838:A,pv}
+
+            {x=787,m=2655,a=1222,s=2876}
+            {x=1679,m=44,a=2067,s=496}
+            {x=2036,m=264,a=79,s=2244}
+            {x=2461,m=1339,a=466,s=291}
+            {x=2127,m=1623,a=2188,s=1013}";
+        assert_eq!(solve(input), Ok(19114));
+
+        let (tree, parts) = parse_input(input).unwrap();
+        // The two verdicts plus one test per conditional rule.
+        assert_eq!(tree.nodes.len(), 16);
+        let accepted = tree.accepted(PartRange::cube(1, 4001));
+        for part in &parts {
+            let inside = accepted.iter().any(|r| r.contains(&part.ratings));
+            assert_eq!(inside, tree.accepts(part));
+            assert_eq!(tree.trace(part).accepted, tree.accepts(part));
+        }
+
+        let trace = tree.trace(&parts[0]);
+        assert_eq!(
+            trace.to_string(),
+            "in -> qqz -> qs -> lnx -> A\n    in: qqz\n    qqz: s>2770:qs\n    qs: lnx\n    lnx: m>1548:A"
+        );
+        let explained = explain(input).unwrap();
+        assert_eq!(explained.lines().filter(|l| !l.starts_with(' ')).count(), 5);
+        assert!(explained.contains("{x=1679,m=44,a=2067,s=496}: in -> px -> rfg -> gd -> R"));
+    }
+
+    #[test]
+    fn test_trace_fallback_only() {
+        let input = r"
+            in{x<5:Y,R}
+            Y{Z}
+            Z{m>1:A,R}
+
+            {x=1,m=2,a=3,s=4}";
+        let (tree, parts) = parse_input(input).unwrap();
+        assert_eq!(
+            tree.trace(&parts[0]).to_string(),
+            "in -> Y -> Z -> A\n    in: x<5:Y\n    Y: Z\n    Z: m>1:A"
+        );
+        assert_eq!(solve(input), Ok(10));
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        let err = solve("in{x<5:a,R}\na{m>1:in,A}").unwrap_err();
+        assert_eq!(err.to_string(), "workflow cycle through 'in'");
+        let err = solve("in{x<5:A,b}").unwrap_err();
+        assert_eq!(err.to_string(), "undefined workflow 'b'");
+        let err = solve("in{x<5:A,s>3:R}").unwrap_err();
+        assert_eq!(err.to_string(), "no fallback rule in 's>3:R'");
+        let err = solve("a{A}").unwrap_err();
+        assert_eq!(err.to_string(), "missing workflow 'in'");
+    }
+}
@@ -0,0 +1,119 @@
+use std::str::FromStr;
+
+use super::day19a::{parse_input, PartRange, CATEGORIES};
+use crate::parse::ParseError;
+
+const RATING_MIN_VALUE: u64 = 1;
+const RATING_MAX_VALUE: u64 = 4000;
+
+/// How `dump_accepted` writes out the accepted ranges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeFormat {
+    /// One range per line, as `x=1..=1415 m=...`.
+    Text,
+    /// A header row, then inclusive bounds for each category per row.
+    Csv,
+}
+
+impl FromStr for RangeFormat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(RangeFormat::Text),
+            "csv" => Ok(RangeFormat::Csv),
+            _ => Err(ParseError::new(s, "invalid range format")),
+        }
+    }
+}
+
+/// The disjoint ranges of ratings that are accepted, in sorted order.
+fn accepted_ranges(input: &str) -> Result<Vec<PartRange>, ParseError> {
+    let (tree, _) = parse_input(input)?;
+    let ratings = PartRange::cube(RATING_MIN_VALUE, RATING_MAX_VALUE + 1);
+    let mut accepted = tree.accepted(ratings);
+    accepted.sort_by_key(|range| range.lo);
+    Ok(accepted)
+}
+
+/// Lists every accepted range and how many combinations it holds, for
+/// checking the answer by hand or in a spreadsheet.
+pub fn dump_accepted(input: &str, format: RangeFormat) -> Result<String, ParseError> {
+    let accepted = accepted_ranges(input)?;
+    let mut lines = Vec::with_capacity(accepted.len() + 1);
+    if format == RangeFormat::Csv {
+        let mut header: Vec<_> = CATEGORIES
+            .iter()
+            .flat_map(|c| [format!("{}_min", c), format!("{}_max", c)])
+            .collect();
+        header.push("combinations".to_string());
+        lines.push(header.join(","));
+    }
+    for range in &accepted {
+        let bounds = (0..CATEGORIES.len()).map(|i| (range.lo[i], range.hi[i] - 1));
+        let line = match format {
+            RangeFormat::Text => {
+                let bounds: Vec<_> = CATEGORIES
+                    .iter()
+                    .zip(bounds)
+                    .map(|(c, (min, max))| format!("{}={}..={}", c, min, max))
+                    .collect();
+                format!("{} ({})", bounds.join(" "), range.volume())
+            }
+            RangeFormat::Csv => {
+                let mut fields: Vec<_> = bounds
+                    .flat_map(|(min, max)| [min.to_string(), max.to_string()])
+                    .collect();
+                fields.push(range.volume().to_string());
+                fields.join(",")
+            }
+        };
+        lines.push(line);
+    }
+    Ok(lines.join("\n"))
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let accepted = accepted_ranges(input)?;
+    Ok(accepted.iter().map(PartRange::volume).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = r"
+            px{a<2006:qkq,m>2090:A,rfg}
+            pv{a>1716:R,A}
+            lnx{m>1548:A,A}
+            rfg{s<537:gd,x>2440:R,A}
+            qs{s>3448:A,lnx}
+            qkq{x<1416:A,crn}
+            crn{x>2662:A,R}
+            in{s<1351:px,qqz}
+            qqz{s>2770:qs,m<1801:hdj,R}
+            gd{a>3333:R,R}
+            hdj{m>838:A,pv}
+
+            {x=787,m=2655,a=1222,s=2876}
+            {x=1679,m=44,a=2067,s=496}
+            {x=2036,m=264,a=79,s=2244}
+            {x=2461,m=1339,a=466,s=291}
+            {x=2127,m=1623,a=2188,s=1013}";
+        assert_eq!(solve(input), Ok(167409079868000));
+
+        let text = dump_accepted(input, RangeFormat::Text).unwrap();
+        let csv = dump_accepted(input, "csv".parse().unwrap()).unwrap();
+        assert_eq!(csv.lines().count(), text.lines().count() + 1);
+        assert!(csv.starts_with("x_min,x_max,m_min,m_max,a_min,a_max,s_min,s_max,combinations\n"));
+        let total: u64 = csv
+            .lines()
+            .skip(1)
+            .map(|line| line.rsplit(',').next().unwrap().parse::<u64>().unwrap())
+            .sum();
+        assert_eq!(total, 167409079868000);
+        assert!(text.lines().all(|line| line.starts_with("x=")));
+    }
+}
@@ -0,0 +1,705 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Write,
+    str::FromStr,
+};
+
+use crate::parse::{self, ParseError};
+
+/// Index of a module in `Machine::modules`.
+pub type ModuleId = u16;
+
+/// Conjunctions remember their inputs in a `u64`, one bit each.
+const MAX_CONJUNCTION_INPUTS: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModuleType {
+    FlipFlop {
+        on: bool,
+    },
+    /// Bit `i` of `high` is set if `inputs[i]` last sent a high pulse.
+    Conjunction {
+        inputs: Vec<ModuleId>,
+        high: u64,
+    },
+    Broadcaster,
+    /// Sends its single low pulse to the broadcaster when pressed.
+    Button,
+    /// Named as an output but never defined, so it ignores every pulse.
+    Sink,
+}
+
+/// A connection to another module, along with the slot the receiving
+/// module knows this one by if it is a conjunction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wire {
+    pub dst: ModuleId,
+    pub slot: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    pub name: String,
+    pub module_type: ModuleType,
+    pub outputs: Vec<Wire>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub modules: Vec<Module>,
+    pub ids: HashMap<String, ModuleId>,
+    pub button: ModuleId,
+}
+
+/// How many low and high pulses were sent from one module to another,
+/// keyed by `(src, dst)`.
+pub type PulseCounts = HashMap<(ModuleId, ModuleId), (usize, usize)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassedPulse {
+    pub src: ModuleId,
+    pub dst: ModuleId,
+    pub pulse: Pulse,
+}
+
+/// Escapes `s` for use between double quotes: quotes and backslashes
+/// always, control characters too if `json`.
+fn escape(s: &str, json: bool) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => write!(escaped, "\\{}", c).unwrap(),
+            c if json && c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A module line as written: its type, name and the names it sends to.
+fn parse_module(s: &str) -> Result<(ModuleType, &str, Vec<&str>), ParseError> {
+    let (input, output) = parse::split_once(s, " -> ")?;
+    let (module_type, name) = if let Some(name) = input.strip_prefix('%') {
+        (ModuleType::FlipFlop { on: false }, name)
+    } else if let Some(name) = input.strip_prefix('&') {
+        let (inputs, high) = (Vec::new(), 0);
+        (ModuleType::Conjunction { inputs, high }, name)
+    } else if input == "broadcaster" {
+        (ModuleType::Broadcaster, input)
+    } else {
+        return Err(ParseError::new(input, "invalid module"));
+    };
+    Ok((module_type, name, output.split(", ").collect()))
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = Vec::new();
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            lines.push(parse_module(line)?);
+        }
+
+        // Defined modules come first, in input order, then the undefined
+        // outputs and the button.
+        let mut modules = Vec::new();
+        let mut ids = HashMap::new();
+        for (module_type, name, _) in &lines {
+            if ids
+                .insert(name.to_string(), modules.len() as ModuleId)
+                .is_some()
+            {
+                return Err(ParseError::new(name, "duplicate module"));
+            }
+            modules.push(Module {
+                name: name.to_string(),
+                module_type: module_type.clone(),
+                outputs: Vec::new(),
+            });
+        }
+        if !ids.contains_key("broadcaster") {
+            return Err(ParseError::missing(s.trim_end(), "broadcaster module"));
+        }
+        let named = lines
+            .iter()
+            .flat_map(|(_, name, outputs)| outputs.iter().chain([name]));
+        if let Some(button) = named.into_iter().find(|&&name| name == "button") {
+            return Err(ParseError::new(button, "reserved module name"));
+        }
+        let outputs = lines.iter().flat_map(|(_, _, outputs)| outputs);
+        for &name in outputs.chain(&["button"]) {
+            if !ids.contains_key(name) {
+                ids.insert(name.to_string(), modules.len() as ModuleId);
+                modules.push(Module {
+                    name: name.to_string(),
+                    module_type: ModuleType::Sink,
+                    outputs: Vec::new(),
+                });
+            }
+        }
+        if modules.len() > ModuleId::MAX as usize {
+            return Err(ParseError::new(s.trim_end(), "too many modules in"));
+        }
+        let button = ids["button"];
+        modules[button as usize].module_type = ModuleType::Button;
+
+        let wires = lines
+            .iter()
+            .map(|(_, src, outputs)| (*src, outputs.clone()))
+            .chain([("button", vec!["broadcaster"])]);
+        for (src, outputs) in wires {
+            let src = ids[src];
+            for dst in outputs {
+                let mut slot = 0;
+                if let ModuleType::Conjunction { inputs, .. } =
+                    &mut modules[ids[dst] as usize].module_type
+                {
+                    if inputs.len() == MAX_CONJUNCTION_INPUTS {
+                        return Err(ParseError::new(dst, "too many inputs to conjunction"));
+                    }
+                    slot = inputs.len() as u8;
+                    inputs.push(src);
+                }
+                let dst = ids[dst];
+                modules[src as usize].outputs.push(Wire { dst, slot });
+            }
+        }
+        Ok(Machine {
+            modules,
+            ids,
+            button,
+        })
+    }
+}
+
+impl Machine {
+    pub fn name(&self, id: ModuleId) -> &str {
+        &self.modules[id as usize].name
+    }
+
+    /// A pulse in the puzzle's notation, like `a -high-> inv`.
+    pub fn describe(&self, passed: &PassedPulse) -> String {
+        let pulse = match passed.pulse {
+            Pulse::High => "high",
+            Pulse::Low => "low",
+        };
+        let (src, dst) = (self.name(passed.src), self.name(passed.dst));
+        format!("{} -{}-> {}", src, pulse, dst)
+    }
+
+    /// Pushes the button once, handing every pulse sent to `observe` in the
+    /// order it is delivered, starting with the button's own pulse.
+    pub fn press(&mut self, mut observe: impl FnMut(&PassedPulse)) {
+        let mut queue = VecDeque::with_capacity(64);
+        let wire = self.modules[self.button as usize].outputs[0];
+        let passed = PassedPulse {
+            src: self.button,
+            dst: wire.dst,
+            pulse: Pulse::Low,
+        };
+        queue.push_back((passed, wire.slot));
+        while let Some((passed, slot)) = queue.pop_front() {
+            observe(&passed);
+
+            let module = &mut self.modules[passed.dst as usize];
+            let pulse = match &mut module.module_type {
+                ModuleType::FlipFlop { on } => {
+                    if passed.pulse == Pulse::High {
+                        continue;
+                    }
+                    *on = !*on;
+                    if *on {
+                        Pulse::High
+                    } else {
+                        Pulse::Low
+                    }
+                }
+                ModuleType::Conjunction { inputs, high } => {
+                    match passed.pulse {
+                        Pulse::High => *high |= 1 << slot,
+                        Pulse::Low => *high &= !(1 << slot),
+                    }
+                    if high.count_ones() as usize == inputs.len() {
+                        Pulse::Low
+                    } else {
+                        Pulse::High
+                    }
+                }
+                ModuleType::Broadcaster | ModuleType::Button => passed.pulse,
+                ModuleType::Sink => continue,
+            };
+            for wire in &module.outputs {
+                let next = PassedPulse {
+                    src: passed.dst,
+                    dst: wire.dst,
+                    pulse,
+                };
+                queue.push_back((next, wire.slot));
+            }
+        }
+    }
+
+    /// Presses the button `presses` times, counting the pulses sent along
+    /// each wire.
+    pub fn count_pulses(&mut self, presses: usize) -> PulseCounts {
+        let mut counts = PulseCounts::new();
+        for _ in 0..presses {
+            self.press(|passed| {
+                let count = counts.entry((passed.src, passed.dst)).or_default();
+                match passed.pulse {
+                    Pulse::Low => count.0 += 1,
+                    Pulse::High => count.1 += 1,
+                }
+            });
+        }
+        counts
+    }
+
+    /// Draws the network in Graphviz DOT. Each module type gets its own
+    /// shape and edges are numbered in the order pulses go out along them.
+    /// Given `counts`, nodes show the pulses they received and edges the
+    /// pulses sent along them instead, as `low/high`.
+    pub fn to_dot(&self, counts: Option<&PulseCounts>) -> String {
+        let mut ids: Vec<ModuleId> = (0..self.modules.len() as ModuleId).collect();
+        ids.sort_by_key(|&id| self.name(id));
+        let mut dot = String::from("digraph machine {\n");
+        for &id in &ids {
+            let (shape, prefix) = match self.modules[id as usize].module_type {
+                ModuleType::FlipFlop { .. } => ("box", "%"),
+                ModuleType::Conjunction { .. } => ("invtriangle", "&"),
+                ModuleType::Broadcaster => ("doublecircle", ""),
+                ModuleType::Button => ("plaintext", ""),
+                ModuleType::Sink => ("octagon", ""),
+            };
+            let name = escape(self.name(id), false);
+            let mut label = format!("{}{}", prefix, name);
+            if let Some(counts) = counts {
+                let (low, high) = counts
+                    .iter()
+                    .filter(|((_, dst), _)| *dst == id)
+                    .fold((0, 0), |acc, (_, &(low, high))| (acc.0 + low, acc.1 + high));
+                write!(label, "\\nlow {} / high {}", low, high).unwrap();
+            }
+            writeln!(
+                dot,
+                "    \"{}\" [shape={}, label=\"{}\"];",
+                name, shape, label
+            )
+            .unwrap();
+        }
+        for &src in &ids {
+            for (i, wire) in self.modules[src as usize].outputs.iter().enumerate() {
+                let label = match counts {
+                    Some(counts) => {
+                        let key = (src, wire.dst);
+                        let (low, high) = counts.get(&key).copied().unwrap_or_default();
+                        format!("{}/{}", low, high)
+                    }
+                    None => (i + 1).to_string(),
+                };
+                let src = escape(self.name(src), false);
+                let dst = escape(self.name(wire.dst), false);
+                writeln!(dot, "    \"{}\" -> \"{}\" [label=\"{}\"];", src, dst, label).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// A pulse between modules known by name, as `NamedMachine` sends them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedPulse {
+    pub src: String,
+    pub dst: String,
+    pub pulse: Pulse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NamedState {
+    FlipFlop { on: bool },
+    Conjunction { inputs: HashMap<String, Pulse> },
+    Broadcaster,
+    Sink,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NamedModule {
+    name: String,
+    state: NamedState,
+    outputs: Vec<String>,
+}
+
+/// The simulator `Machine` replaced, which looks modules up by name, clones
+/// the names into every pulse and queues pulses in a `Vec` taken from the
+/// front. Kept to compare `Machine::press` against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedMachine {
+    modules: HashMap<String, NamedModule>,
+}
+
+impl From<&Machine> for NamedMachine {
+    fn from(machine: &Machine) -> Self {
+        let modules = machine
+            .modules
+            .iter()
+            .filter(|module| module.module_type != ModuleType::Button)
+            .map(|module| {
+                let state = match &module.module_type {
+                    ModuleType::FlipFlop { on } => NamedState::FlipFlop { on: *on },
+                    ModuleType::Conjunction { inputs, .. } => NamedState::Conjunction {
+                        inputs: inputs
+                            .iter()
+                            .map(|&id| (machine.name(id).to_string(), Pulse::Low))
+                            .collect(),
+                    },
+                    ModuleType::Broadcaster => NamedState::Broadcaster,
+                    ModuleType::Button | ModuleType::Sink => NamedState::Sink,
+                };
+                let outputs = module
+                    .outputs
+                    .iter()
+                    .map(|wire| machine.name(wire.dst).to_string())
+                    .collect();
+                let name = module.name.clone();
+                let module = NamedModule {
+                    name: name.clone(),
+                    state,
+                    outputs,
+                };
+                (name, module)
+            })
+            .collect();
+        NamedMachine { modules }
+    }
+}
+
+impl NamedMachine {
+    /// Pushes the button once, like `Machine::press`.
+    pub fn press(&mut self, mut observe: impl FnMut(&NamedPulse)) {
+        let mut queue = vec![NamedPulse {
+            src: "button".to_string(),
+            dst: "broadcaster".to_string(),
+            pulse: Pulse::Low,
+        }];
+        while !queue.is_empty() {
+            let passed = queue.remove(0);
+            observe(&passed);
+
+            let module = self.modules.get_mut(&passed.dst).unwrap();
+            let pulse = match &mut module.state {
+                NamedState::FlipFlop { on } => {
+                    if passed.pulse == Pulse::High {
+                        continue;
+                    }
+                    *on = !*on;
+                    if *on {
+                        Pulse::High
+                    } else {
+                        Pulse::Low
+                    }
+                }
+                NamedState::Conjunction { inputs } => {
+                    inputs.insert(passed.src, passed.pulse);
+                    if inputs.values().all(|&p| p == Pulse::High) {
+                        Pulse::Low
+                    } else {
+                        Pulse::High
+                    }
+                }
+                NamedState::Broadcaster => passed.pulse,
+                NamedState::Sink => continue,
+            };
+            for output in &module.outputs {
+                queue.push(NamedPulse {
+                    src: module.name.clone(),
+                    dst: output.clone(),
+                    pulse,
+                });
+            }
+        }
+    }
+}
+
+/// A pulse as the recorder logged it: which press sent it and where it came
+/// in that press's delivery order, counting from 0.
+#[derive(Debug, Clone)]
+pub struct TraceEvent {
+    pub press: usize,
+    pub seq: usize,
+    pub passed: PassedPulse,
+}
+
+/// Which pulses to record. Unset fields match anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceFilter {
+    pub src: Option<ModuleId>,
+    pub dst: Option<ModuleId>,
+    pub pulse: Option<Pulse>,
+}
+
+/// Presses the button on a machine while logging the pulses that pass the
+/// filter, and keeps a copy of the machine every `interval` presses so its
+/// state after any press can be brought back.
+pub struct Recorder {
+    pub filter: TraceFilter,
+    pub events: Vec<TraceEvent>,
+    pub machine: Machine,
+    presses: usize,
+    interval: usize,
+    /// `checkpoints[i]` is the machine after `i * interval` presses.
+    checkpoints: Vec<Machine>,
+}
+
+impl TraceFilter {
+    /// Reads comma-separated conditions like `src=inv,pulse=high`, naming
+    /// modules of `machine`.
+    pub fn parse(s: &str, machine: &Machine) -> Result<TraceFilter, ParseError> {
+        let mut filter = TraceFilter::default();
+        for condition in s.split(',').filter(|c| !c.is_empty()) {
+            let (key, value) = parse::split_once(condition, "=")?;
+            let module = || {
+                let id = machine.ids.get(value).copied();
+                id.ok_or_else(|| ParseError::new(value, "unknown module"))
+            };
+            match key {
+                "src" => filter.src = Some(module()?),
+                "dst" => filter.dst = Some(module()?),
+                "pulse" => {
+                    filter.pulse = Some(match value {
+                        "low" => Pulse::Low,
+                        "high" => Pulse::High,
+                        _ => return Err(ParseError::new(value, "invalid pulse")),
+                    })
+                }
+                _ => return Err(ParseError::new(key, "invalid trace filter")),
+            }
+        }
+        Ok(filter)
+    }
+
+    pub fn matches(&self, passed: &PassedPulse) -> bool {
+        self.src.is_none_or(|src| src == passed.src)
+            && self.dst.is_none_or(|dst| dst == passed.dst)
+            && self.pulse.is_none_or(|pulse| pulse == passed.pulse)
+    }
+}
+
+impl TraceEvent {
+    pub fn to_json(&self, machine: &Machine) -> String {
+        let pulse = match self.passed.pulse {
+            Pulse::High => "high",
+            Pulse::Low => "low",
+        };
+        let src = escape(machine.name(self.passed.src), true);
+        let dst = escape(machine.name(self.passed.dst), true);
+        format!(
+            r#"{{"press":{},"seq":{},"src":"{}","dst":"{}","pulse":"{}"}}"#,
+            self.press, self.seq, src, dst, pulse
+        )
+    }
+}
+
+impl Recorder {
+    pub fn new(machine: Machine, filter: TraceFilter, interval: usize) -> Self {
+        assert!(interval > 0, "Checkpoint interval must be positive");
+        Recorder {
+            filter,
+            events: Vec::new(),
+            checkpoints: vec![machine.clone()],
+            machine,
+            presses: 0,
+            interval,
+        }
+    }
+
+    pub fn press(&mut self) {
+        self.presses += 1;
+        let (press, filter, events) = (self.presses, &self.filter, &mut self.events);
+        let mut seq = 0;
+        self.machine.press(|passed| {
+            if filter.matches(passed) {
+                events.push(TraceEvent {
+                    press,
+                    seq,
+                    passed: *passed,
+                });
+            }
+            seq += 1;
+        });
+        if self.presses.is_multiple_of(self.interval) {
+            self.checkpoints.push(self.machine.clone());
+        }
+    }
+
+    /// The machine as it was after `press` presses, replayed from the last
+    /// checkpoint before it, or `None` if it has not been pressed that often.
+    pub fn restore(&self, press: usize) -> Option<Machine> {
+        if press > self.presses {
+            return None;
+        }
+        let mut machine = self.checkpoints[press / self.interval].clone();
+        for _ in 0..press % self.interval {
+            machine.press(|_| ());
+        }
+        Some(machine)
+    }
+
+    /// The recorded events in JSON Lines, one object per pulse.
+    pub fn to_json_lines(&self) -> String {
+        self.events
+            .iter()
+            .map(|event| event.to_json(&self.machine) + "\n")
+            .collect()
+    }
+}
+
+/// Records the pulses matching `filter` over the first `presses` presses,
+/// as JSON Lines.
+pub fn trace(machine: Machine, filter: TraceFilter, presses: usize) -> String {
+    let mut recorder = Recorder::new(machine, filter, 100);
+    for _ in 0..presses {
+        recorder.press();
+    }
+    recorder.to_json_lines()
+}
+
+/// The machine's network in Graphviz DOT, annotated with the pulses sent
+/// during the first `presses` presses if given.
+pub fn to_dot(input: &str, presses: Option<usize>) -> Result<String, ParseError> {
+    let mut machine: Machine = input.parse()?;
+    let counts = presses.map(|presses| machine.count_pulses(presses));
+    Ok(machine.to_dot(counts.as_ref()))
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let mut machine: Machine = input.parse()?;
+    let counts = machine.count_pulses(1000);
+    let (low_count, high_count) = counts
+        .values()
+        .fold((0, 0), |acc, &(low, high)| (acc.0 + low, acc.1 + high));
+    Ok(high_count * low_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = r"
+            broadcaster -> a, b, c
+            %a -> b
+            %b -> c
+            %c -> inv
+            &inv -> a";
+        assert_eq!(solve(input), Ok(32000000));
+
+        let input = r"
+            broadcaster -> a
+            %a -> inv, con
+            &inv -> b
+            %b -> con
+            &con -> output";
+        assert_eq!(solve(input), Ok(11687500));
+
+        let machine: Machine = input.parse().unwrap();
+        assert_eq!(machine.modules.len(), 7);
+        let con = &machine.modules[machine.ids["con"] as usize];
+        let expected = vec![machine.ids["a"], machine.ids["b"]];
+        assert!(
+            matches!(&con.module_type, ModuleType::Conjunction { inputs, .. } if *inputs == expected)
+        );
+        let b = &machine.modules[machine.ids["b"] as usize];
+        let wire = Wire {
+            dst: machine.ids["con"],
+            slot: 1,
+        };
+        assert_eq!(b.outputs, vec![wire]);
+
+        let dot = to_dot(input, None).unwrap();
+        assert!(dot.starts_with("digraph machine {\n"));
+        assert!(dot.contains("    \"con\" [shape=invtriangle, label=\"&con\"];\n"));
+        assert!(dot.contains("    \"output\" [shape=octagon, label=\"output\"];\n"));
+        assert!(dot.contains("    \"a\" -> \"con\" [label=\"2\"];\n"));
+
+        let dot = to_dot(input, Some(1)).unwrap();
+        assert!(dot.contains("    \"b\" [shape=box, label=\"%b\\nlow 1 / high 0\"];\n"));
+        assert!(dot.contains("    \"button\" -> \"broadcaster\" [label=\"1/0\"];\n"));
+        assert!(dot.contains("    \"a\" -> \"inv\" [label=\"0/1\"];\n"));
+
+        let mut machine = machine;
+        let mut named = NamedMachine::from(&machine);
+        for _ in 0..4 {
+            let (mut pulses, mut named_pulses) = (Vec::new(), Vec::new());
+            machine.press(|passed| pulses.push(*passed));
+            named.press(|passed| named_pulses.push(passed.clone()));
+            let pulses: Vec<_> = pulses
+                .iter()
+                .map(|p| (machine.name(p.src), machine.name(p.dst), p.pulse))
+                .collect();
+            let named_pulses: Vec<_> = named_pulses
+                .iter()
+                .map(|p| (p.src.as_str(), p.dst.as_str(), p.pulse))
+                .collect();
+            assert_eq!(pulses, named_pulses);
+        }
+
+        let err = "broadcaster -> a\n%a -> b\n&a -> b".parse::<Machine>();
+        assert_eq!(err.unwrap_err().to_string(), "duplicate module 'a'");
+    }
+
+    #[test]
+    fn test_recorder() {
+        let input = r"
+            broadcaster -> a
+            %a -> inv, con
+            &inv -> b
+            %b -> con
+            &con -> output";
+        let machine: Machine = input.parse().unwrap();
+        let filter = TraceFilter::parse("dst=con,pulse=high", &machine).unwrap();
+        let mut recorder = Recorder::new(machine.clone(), filter, 3);
+        for _ in 0..7 {
+            recorder.press();
+        }
+        let first = &recorder.events[0];
+        assert_eq!((first.press, first.seq), (1, 3));
+        assert_eq!(machine.describe(&first.passed), "a -high-> con");
+        let con = machine.ids["con"];
+        assert!(recorder.events.iter().all(|e| e.passed.dst == con));
+        let json = recorder.to_json_lines();
+        assert_eq!(
+            json.lines().next(),
+            Some(r#"{"press":1,"seq":3,"src":"a","dst":"con","pulse":"high"}"#)
+        );
+
+        let mut replayed = machine.clone();
+        for press in 0..=7 {
+            assert_eq!(recorder.restore(press).as_ref(), Some(&replayed));
+            replayed.press(|_| ());
+        }
+        assert_eq!(recorder.restore(8), None);
+        assert!(TraceFilter::parse("pulse=medium", &machine).is_err());
+        let err = TraceFilter::parse("src=nope", &machine).unwrap_err();
+        assert_eq!(err.to_string(), "unknown module 'nope'");
+
+        let machine: Machine = "broadcaster -> a\"b\n%a\"b -> c\\d".parse().unwrap();
+        let json = trace(machine.clone(), TraceFilter::default(), 1);
+        assert_eq!(
+            json.lines().nth(1),
+            Some(r#"{"press":1,"seq":1,"src":"broadcaster","dst":"a\"b","pulse":"low"}"#)
+        );
+        let dot = machine.to_dot(None);
+        assert!(dot.contains("    \"a\\\"b\" [shape=box, label=\"%a\\\"b\"];\n"));
+        assert!(dot.contains("    \"a\\\"b\" -> \"c\\\\d\" [label=\"1\"];\n"));
+        assert_eq!(escape("x\ty", true), "x\\u0009y");
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::day20a::{Machine, ModuleId, ModuleType, Pulse};
+use crate::parse::ParseError;
+
+/// How often one input of the conjunction feeding the target sends it a high pulse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Period {
+    pub name: String,
+    pub first: usize,
+    pub len: usize,
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} goes high first at press {}, then every {} presses",
+            self.name, self.first, self.len
+        )
+    }
+}
+
+/// Upper bound on presses spent looking for periods before giving up.
+const MAX_PRESSES: usize = 1_000_000;
+
+/// The target only receives a low pulse once every input of the conjunction
+/// feeding it is high during the same press. Each input is the output of an
+/// independent counter, so watch when each one goes high and find its period.
+pub fn find_periods(machine: &mut Machine, target: &str) -> Result<Vec<Period>, ParseError> {
+    let id = machine.ids.get(target).copied();
+    let target = id.ok_or_else(|| ParseError::new(target, "unknown module"))?;
+    let feeders: Vec<_> = (0..machine.modules.len())
+        .filter(|&id| machine.modules[id].outputs.iter().any(|w| w.dst == target))
+        .collect();
+    let feeder = match feeders[..] {
+        [feeder] => feeder,
+        _ => {
+            let name = machine.name(target);
+            return Err(ParseError::new(name, "expected exactly one module feeding"));
+        }
+    };
+    let mut inputs = match &machine.modules[feeder].module_type {
+        ModuleType::Conjunction { inputs, .. } => inputs.clone(),
+        _ => {
+            let name = &machine.modules[feeder].name;
+            return Err(ParseError::new(name, "expected a conjunction, found"));
+        }
+    };
+    inputs.sort_by_key(|&id| machine.name(id));
+    let feeder = feeder as ModuleId;
+
+    // The presses at which each input has sent a high pulse so far.
+    let mut seen: Vec<Vec<usize>> = vec![vec![]; inputs.len()];
+    for press in 1..=MAX_PRESSES {
+        machine.press(|passed| {
+            if passed.dst != feeder || passed.pulse != Pulse::High {
+                return;
+            }
+            let i = inputs.iter().position(|n| *n == passed.src).unwrap();
+            if seen[i].last() != Some(&press) {
+                seen[i].push(press);
+            }
+        });
+        if seen.iter().all(|presses| presses.len() >= 2) {
+            break;
+        }
+    }
+
+    inputs
+        .into_iter()
+        .zip(seen)
+        .map(|(id, presses)| match presses[..] {
+            [first, second, ..] => Ok(Period {
+                name: machine.name(id).to_string(),
+                first,
+                len: second - first,
+            }),
+            _ => Err(ParseError::unsolvable(format!(
+                "{} has no period within {} presses",
+                machine.name(id),
+                MAX_PRESSES
+            ))),
+        })
+        .collect()
+}
+
+/// The periods of the counters that together send rx a low pulse.
+pub fn periods(input: &str) -> Result<Vec<Period>, ParseError> {
+    let mut machine: Machine = input.parse()?;
+    find_periods(&mut machine, "rx")
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let periods = periods(input)?;
+    // Combining with a plain LCM only works if every counter restarts at
+    // press 0, which is what the puzzle's counters do.
+    if let Some(period) = periods.iter().find(|p| p.first != p.len) {
+        return Err(ParseError::unsolvable(format!(
+            "counter {} does not restart at press 0",
+            period.name
+        )));
+    }
+    periods
+        .iter()
+        .map(|p| p.len)
+        .reduce(num::integer::lcm)
+        .ok_or_else(|| ParseError::missing("", "inputs to the module feeding 'rx'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        // Two binary counters, of three and four bits, wired like the
+        // puzzle's: each conjunction watches the bits set in its period and
+        // resets the counter when they are all high, so they fire every 7
+        // (0b111) and 11 (0b1011) presses.
+        let input = r"
+            broadcaster -> a1, b1
+            %a1 -> a2, ca
+            %a2 -> a3, ca
+            %a3 -> ca
+            &ca -> a1, ia
+            &ia -> hub
+            %b1 -> b2, cb
+            %b2 -> b3, cb
+            %b3 -> b4
+            %b4 -> cb
+            &cb -> b1, b3, ib
+            &ib -> hub
+            &hub -> rx";
+        let periods = periods(input).unwrap();
+        let lens: Vec<_> = periods
+            .iter()
+            .map(|p| (p.name.as_str(), p.first, p.len))
+            .collect();
+        assert_eq!(lens, vec![("ia", 7, 7), ("ib", 11, 11)]);
+
+        // Compare against simulating until rx actually gets a low pulse.
+        let mut machine: Machine = input.parse().unwrap();
+        let rx = machine.ids["rx"];
+        let mut presses = 0;
+        let mut done = false;
+        while !done {
+            presses += 1;
+            machine.press(|passed| done |= passed.dst == rx && passed.pulse == Pulse::Low);
+        }
+        assert_eq!(solve(input), Ok(presses));
+        assert_eq!(presses, 77);
+
+        let err = solve("broadcaster -> a\n%a -> rx").unwrap_err();
+        assert_eq!(err.to_string(), "expected a conjunction, found 'a'");
+    }
+}
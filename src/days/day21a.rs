@@ -0,0 +1,111 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    geometry::Point2,
+    grid::{Grid, Pos},
+    parse::ParseError,
+};
+
+#[derive(PartialEq, Eq)]
+pub(crate) enum Tile {
+    Plot,
+    Rock,
+}
+
+pub(crate) struct Garden {
+    pub tiles: Grid<Tile>,
+    pub start: Pos,
+}
+
+impl FromStr for Garden {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::try_parse(s, |c| match c {
+            '.' | 'S' | '#' => Ok(c),
+            _ => Err(ParseError::invalid_char(c, "tile")),
+        })?;
+        let start = chars
+            .position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::missing(s.trim_end(), "start 'S'"))?;
+        let tiles = chars.map(|&c| match c {
+            '#' => Tile::Rock,
+            _ => Tile::Plot,
+        });
+        Ok(Garden { tiles, start })
+    }
+}
+
+impl Garden {
+    /// Looks up a tile on the infinitely repeating map.
+    pub(crate) fn wrapped(&self, p: Point2) -> &Tile {
+        let x = p.x.rem_euclid(self.tiles.width() as i64) as usize;
+        let y = p.y.rem_euclid(self.tiles.height() as i64) as usize;
+        &self.tiles[(x, y)]
+    }
+}
+
+impl Display for Garden {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let chars = self.tiles.map_with_pos(|pos, tile| {
+            if pos == self.start {
+                'S'
+            } else {
+                match tile {
+                    Tile::Plot => '.',
+                    Tile::Rock => '#',
+                }
+            }
+        });
+        write!(f, "{}", chars)
+    }
+}
+
+pub fn solve(input: &str, steps: usize) -> Result<usize, ParseError> {
+    let garden: Garden = input.parse()?;
+    let mut visited: FxHashSet<(Pos, usize)> = FxHashSet::default();
+    let mut queue = vec![(garden.start, 0)];
+    while let Some((point, cur_steps)) = queue.pop() {
+        if visited.contains(&(point, cur_steps)) {
+            continue;
+        }
+        visited.insert((point, cur_steps));
+        if cur_steps == steps {
+            continue;
+        }
+        for adjacent in garden.tiles.neighbors4(point) {
+            if garden.tiles[adjacent] == Tile::Rock {
+                continue;
+            }
+            queue.push((adjacent, cur_steps + 1));
+        }
+    }
+    Ok(visited.iter().filter(|(_, s)| *s == steps).count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            ...........
+            .....###.#.
+            .###.##..#.
+            ..#.#...#..
+            ....#.#....
+            .##..S####.
+            .##..#...#.
+            .......##..
+            .##.#.####.
+            .##..##.##.
+            ...........";
+        assert_eq!(solve(input, 6), Ok(16));
+    }
+}
@@ -0,0 +1,125 @@
+use rustc_hash::FxHashSet;
+
+use super::day21a::{Garden, Tile};
+use crate::{geometry::Point2, parse::ParseError};
+
+/// How many consecutive equal second differences are needed before the
+/// sampled counts are trusted to be quadratic.
+const STABLE_DIFFS: usize = 3;
+
+/// Brute force: `counts[s]` is the number of plots reachable in exactly `s`
+/// steps, for every `s` up to `max_steps`. A plot reached in `d` steps can
+/// also be reached in `d + 2`, so each BFS layer adds to every later step
+/// count of the same parity.
+fn reachable_counts(garden: &Garden, max_steps: usize) -> Vec<usize> {
+    let start = Point2::new(garden.start.0 as i64, garden.start.1 as i64);
+    let mut visited = FxHashSet::default();
+    visited.insert(start);
+    let mut frontier = vec![start];
+    let mut counts = vec![1];
+    for steps in 1..=max_steps {
+        let mut next = vec![];
+        for point in frontier {
+            for adjacent in point.neighbors4() {
+                if *garden.wrapped(adjacent) != Tile::Rock && visited.insert(adjacent) {
+                    next.push(adjacent);
+                }
+            }
+        }
+        let same_parity = if steps >= 2 { counts[steps - 2] } else { 0 };
+        counts.push(same_parity + next.len());
+        frontier = next;
+    }
+    counts
+}
+
+/// Once the BFS front has crossed a few copies of the map it repeats itself
+/// every map width, so sampling every `width` steps gives a sequence that is
+/// eventually quadratic. Sample until its second differences settle, then
+/// extrapolate the rest of the way. A map that is not square repeats as a
+/// square of side the least common multiple of its width and height.
+fn extrapolate(garden: &Garden, steps: usize) -> usize {
+    let period = num::integer::lcm(garden.tiles.width(), garden.tiles.height());
+    let offset = steps % period;
+    let mut samples = 4 + STABLE_DIFFS;
+    loop {
+        let max_sample = offset + (samples - 1) * period;
+        if max_sample >= steps {
+            return reachable_counts(garden, steps)[steps];
+        }
+        let counts = reachable_counts(garden, max_sample);
+        let values: Vec<_> = (0..samples)
+            .map(|k| counts[offset + k * period] as i64)
+            .collect();
+        let diffs: Vec<_> = values.windows(2).map(|w| w[1] - w[0]).collect();
+        let second: Vec<_> = diffs.windows(2).map(|w| w[1] - w[0]).collect();
+        let stable = &second[second.len() - STABLE_DIFFS..];
+        if stable.iter().all(|&d| d == stable[0]) {
+            // Walk the sequence forward from the last sample in closed form.
+            let n = ((steps - max_sample) / period) as i64;
+            let value = values[samples - 1];
+            let diff = diffs[diffs.len() - 1];
+            return (value + n * diff + n * (n + 1) / 2 * stable[0]) as usize;
+        }
+        samples *= 2;
+    }
+}
+
+pub fn solve(input: &str, steps: usize) -> Result<usize, ParseError> {
+    let garden: Garden = input.parse()?;
+    Ok(extrapolate(&garden, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ...........";
+
+    #[test]
+    fn test_reachable_counts() {
+        let garden: Garden = INPUT.parse().unwrap();
+        let counts = reachable_counts(&garden, 100);
+        assert_eq!(counts[6], 16);
+        assert_eq!(counts[10], 50);
+        assert_eq!(counts[50], 1594);
+        assert_eq!(counts[100], 6536);
+    }
+
+    #[test]
+    fn test_solve() {
+        let garden: Garden = INPUT.parse().unwrap();
+        let counts = reachable_counts(&garden, 500);
+        for steps in [6, 10, 50, 100, 123, 250, 371, 500] {
+            assert_eq!(extrapolate(&garden, steps), counts[steps]);
+        }
+        assert_eq!(solve(INPUT, 1000), Ok(668697));
+        assert_eq!(solve(INPUT, 5000), Ok(16733044));
+    }
+
+    #[test]
+    fn test_not_square() {
+        let garden: Garden = "
+            ..#...
+            .#..#.
+            ...S..
+            #....."
+            .parse()
+            .unwrap();
+        let counts = reachable_counts(&garden, 400);
+        for steps in [7, 100, 250, 399, 400] {
+            assert_eq!(extrapolate(&garden, steps), counts[steps]);
+        }
+    }
+}
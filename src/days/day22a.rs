@@ -0,0 +1,243 @@
+use std::{
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr,
+};
+
+use crate::{
+    geometry::Point3,
+    parse::{self, ParseError},
+};
+
+/// The axis a brick extends along. Single cubes count as `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum BrickType {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Brick {
+    pub start: Point3,
+    pub len: usize,
+    pub brick_type: BrickType,
+}
+
+/// The bricks after they have all fallen, and which rest on which. Bricks
+/// keep their input order, and the support lists index into it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Stack {
+    pub bricks: Vec<Brick>,
+    /// `supports[i]` lists the bricks resting directly on brick `i`.
+    pub supports: Vec<Vec<usize>>,
+    /// `supported_by[i]` lists the bricks brick `i` rests directly on, and
+    /// is empty for bricks on the ground.
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl FromStr for Brick {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = parse::split_once(s, "~")?;
+        let a: Point3 = a.parse()?;
+        let b: Point3 = b.parse()?;
+        if b < a {
+            return Err(ParseError::new(s, "brick ends out of order"));
+        }
+        if a.x < 0 || a.y < 0 || a.z < 1 {
+            return Err(ParseError::new(s, "brick outside the stacking area"));
+        }
+        let (brick_type, len) = match (a.x == b.x, a.y == b.y, a.z == b.z) {
+            (true, true, _) => (BrickType::Z, b.z - a.z + 1),
+            (true, false, true) => (BrickType::Y, b.y - a.y + 1),
+            (false, true, true) => (BrickType::X, b.x - a.x + 1),
+            _ => return Err(ParseError::new(s, "brick is not a straight line")),
+        };
+        Ok(Brick {
+            start: a,
+            len: len as usize,
+            brick_type,
+        })
+    }
+}
+
+impl Display for Brick {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (a, b) = (self.start, self.end());
+        write!(f, "{},{},{}~{},{},{}", a.x, a.y, a.z, b.x, b.y, b.z)
+    }
+}
+
+impl Brick {
+    /// The far corner of the brick, inclusive.
+    pub fn end(&self) -> Point3 {
+        let len = self.len as i64 - 1;
+        self.start
+            + match self.brick_type {
+                BrickType::X => Point3::new(len, 0, 0),
+                BrickType::Y => Point3::new(0, len, 0),
+                BrickType::Z => Point3::new(0, 0, len),
+            }
+    }
+
+    /// The `(x, y)` columns the brick occupies.
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (start, end) = (self.start, self.end());
+        (start.x..=end.x)
+            .flat_map(move |x| (start.y..=end.y).map(move |y| (x as usize, y as usize)))
+    }
+}
+
+impl Stack {
+    /// Lets every brick fall as far as it can. Bricks are dropped lowest
+    /// first, so each one lands on the highest brick under its footprint,
+    /// which a height map of the top of every column gives directly.
+    pub fn settle(bricks: Vec<Brick>) -> Stack {
+        let width = bricks
+            .iter()
+            .map(|b| b.end().x as usize + 1)
+            .max()
+            .unwrap_or(0);
+        let depth = bricks
+            .iter()
+            .map(|b| b.end().y as usize + 1)
+            .max()
+            .unwrap_or(0);
+        // The height of each column and the brick on top of it, if any.
+        let mut tops: Vec<(i64, Option<usize>)> = vec![(0, None); width * depth];
+        let mut order: Vec<usize> = (0..bricks.len()).collect();
+        order.sort_by_key(|&i| bricks[i].start.z);
+
+        let mut stack = Stack {
+            supports: vec![Vec::new(); bricks.len()],
+            supported_by: vec![Vec::new(); bricks.len()],
+            bricks,
+        };
+        for i in order {
+            let cells: Vec<_> = stack.bricks[i]
+                .footprint()
+                .map(|(x, y)| y * width + x)
+                .collect();
+            let rest = cells.iter().map(|&cell| tops[cell].0).max().unwrap();
+            let mut below: Vec<_> = cells
+                .iter()
+                .filter(|&&cell| tops[cell].0 == rest)
+                .filter_map(|&cell| tops[cell].1)
+                .collect();
+            below.sort_unstable();
+            below.dedup();
+            for &j in &below {
+                stack.supports[j].push(i);
+            }
+            stack.supported_by[i] = below;
+
+            let brick = &mut stack.bricks[i];
+            brick.start.z = rest + 1;
+            let top = brick.end().z;
+            for cell in cells {
+                tops[cell] = (top, Some(i));
+            }
+        }
+        stack
+    }
+
+    /// Writes the stack as a Wavefront OBJ model with one box per brick.
+    /// Viewers expect `y` up, so the stack's `z` becomes `y`. Given
+    /// `toppled`, vertices are coloured from grey for bricks that bring
+    /// nothing down to red for the one that brings down the most.
+    pub fn to_obj(&self, toppled: Option<&[usize]>) -> String {
+        let most = toppled
+            .and_then(|toppled| toppled.iter().max().copied())
+            .unwrap_or(0)
+            .max(1);
+        let mut obj = format!("# {} settled bricks\n", self.bricks.len());
+        for (i, brick) in self.bricks.iter().enumerate() {
+            let (near, far) = (brick.start, brick.end() + Point3::new(1, 1, 1));
+            let colour = toppled.map(|toppled| match toppled[i] {
+                0 => (0.6, 0.6, 0.6),
+                n => (1.0, 1.0 - n as f64 / most as f64, 0.0),
+            });
+            writeln!(obj, "o brick{} {}", i, brick).unwrap();
+            for corner in 0..8 {
+                let x = if corner & 1 == 0 { near.x } else { far.x };
+                let y = if corner & 2 == 0 { near.y } else { far.y };
+                let z = if corner & 4 == 0 { near.z } else { far.z };
+                write!(obj, "v {} {} {}", x, z, -y).unwrap();
+                if let Some((r, g, b)) = colour {
+                    write!(obj, " {:.3} {:.3} {:.3}", r, g, b).unwrap();
+                }
+                obj.push('\n');
+            }
+            for face in BOX_FACES {
+                let corners: Vec<_> = face.iter().map(|c| (8 * i + c + 1).to_string()).collect();
+                writeln!(obj, "f {}", corners.join(" ")).unwrap();
+            }
+        }
+        obj
+    }
+
+    /// Whether brick `i` can be taken out without any other brick falling.
+    pub fn is_removable(&self, i: usize) -> bool {
+        self.supports[i]
+            .iter()
+            .all(|&j| self.supported_by[j].len() >= 2)
+    }
+}
+
+/// The faces of a box as corners numbered by bits: 1 for far `x`, 2 for far
+/// `y`, 4 for far `z`. Each is wound counter-clockwise seen from outside.
+const BOX_FACES: [[usize; 4]; 6] = [
+    [0, 2, 3, 1],
+    [4, 5, 7, 6],
+    [0, 1, 5, 4],
+    [2, 6, 7, 3],
+    [0, 4, 6, 2],
+    [1, 3, 7, 5],
+];
+
+pub(crate) fn parse_input(input: &str) -> Result<Stack, ParseError> {
+    Ok(Stack::settle(parse::lines(input, str::parse)?))
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let stack = parse_input(input)?;
+    Ok((0..stack.bricks.len())
+        .filter(|&i| stack.is_removable(i))
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            1,0,1~1,2,1
+            0,0,2~2,0,2
+            0,2,3~2,2,3
+            0,0,4~0,2,4
+            2,0,5~2,2,5
+            0,1,6~2,1,6
+            1,1,8~1,1,9";
+        assert_eq!(solve(input), Ok(5));
+
+        let stack = parse_input(input).unwrap();
+        assert_eq!(stack.supports[0], vec![1, 2]);
+        assert_eq!(stack.supported_by[3], vec![1, 2]);
+        assert_eq!(stack.supported_by[6], vec![5]);
+        // The vertical brick falls from z=8 onto brick 5.
+        assert_eq!(stack.bricks[6].start, Point3::new(1, 1, 5));
+        assert_eq!(stack.bricks[6].end(), Point3::new(1, 1, 6));
+
+        let obj = stack.to_obj(None);
+        assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 7 * 8);
+        assert_eq!(obj.lines().filter(|l| l.starts_with("f ")).count(), 7 * 6);
+        assert!(obj.contains("o brick6 1,1,5~1,1,6\nv 1 5 -1\nv 2 5 -1\n"));
+        assert!(obj.ends_with("f 50 52 56 54\n"));
+
+        let err = "1,0,1~2,1,1".parse::<Brick>().unwrap_err();
+        assert_eq!(err.message, "brick is not a straight line");
+    }
+}
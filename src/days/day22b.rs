@@ -0,0 +1,166 @@
+use super::day22a::{parse_input, Stack};
+use crate::parse::ParseError;
+
+/// The dominator tree of the support graph, rooted at the ground. Brick `j`
+/// sits under brick `i` in the tree when every chain of supports from the
+/// ground up to `j` passes through `i`, which is exactly when removing `i`
+/// brings `j` down.
+struct DominatorTree {
+    /// The immediate dominator of each brick, or `None` for the ground.
+    parent: Vec<Option<usize>>,
+    /// The number of bricks in each brick's subtree, itself included.
+    sizes: Vec<usize>,
+}
+
+impl DominatorTree {
+    /// Builds the tree in one pass over the bricks from the bottom up. In a
+    /// DAG the immediate dominator of a brick is the lowest common ancestor
+    /// of the bricks it rests on, found here by binary lifting.
+    fn new(stack: &Stack) -> DominatorTree {
+        let n = stack.bricks.len();
+        let ground = n;
+        let levels = (usize::BITS - n.leading_zeros()).max(1) as usize;
+        // `up[k][v]` is the ancestor `2^k` levels above `v`, stopping at the
+        // ground.
+        let mut up = vec![vec![ground; n + 1]; levels];
+        let mut depth = vec![0; n + 1];
+
+        let lca = |up: &[Vec<usize>], depth: &[usize], mut a: usize, mut b: usize| {
+            if depth[a] < depth[b] {
+                (a, b) = (b, a);
+            }
+            for k in (0..levels).rev() {
+                if depth[a] - depth[b] >= 1 << k {
+                    a = up[k][a];
+                }
+            }
+            if a == b {
+                return a;
+            }
+            for k in (0..levels).rev() {
+                if up[k][a] != up[k][b] {
+                    a = up[k][a];
+                    b = up[k][b];
+                }
+            }
+            up[0][a]
+        };
+
+        // Supporting bricks always start lower, so this is a topological
+        // order of the support graph.
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by_key(|&i| stack.bricks[i].start.z);
+        for &i in &order {
+            let idom = stack.supported_by[i]
+                .iter()
+                .copied()
+                .reduce(|a, b| lca(&up, &depth, a, b))
+                .unwrap_or(ground);
+            depth[i] = depth[idom] + 1;
+            up[0][i] = idom;
+            for k in 1..levels {
+                up[k][i] = up[k - 1][up[k - 1][i]];
+            }
+        }
+
+        let parent: Vec<_> = (0..n)
+            .map(|i| Some(up[0][i]).filter(|&p| p != ground))
+            .collect();
+        let mut sizes = vec![1; n];
+        for &i in order.iter().rev() {
+            if let Some(p) = parent[i] {
+                sizes[p] += sizes[i];
+            }
+        }
+        DominatorTree { parent, sizes }
+    }
+
+    /// How many other bricks fall if brick `i` is removed.
+    fn toppled(&self, i: usize) -> usize {
+        self.sizes[i] - 1
+    }
+}
+
+/// Lists every brick in input order with how many others fall if it is
+/// removed, and the nearest brick below it whose removal would bring it
+/// down.
+pub fn topple_report(input: &str) -> Result<String, ParseError> {
+    let stack = parse_input(input)?;
+    let tree = DominatorTree::new(&stack);
+    let lines: Vec<_> = stack
+        .bricks
+        .iter()
+        .enumerate()
+        .map(|(i, brick)| {
+            let held_by = match tree.parent[i] {
+                Some(p) => format!(", held up by {}", stack.bricks[p]),
+                None => String::new(),
+            };
+            format!("{} topples {}{}", brick, tree.toppled(i), held_by)
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+/// The settled stack as a Wavefront OBJ model, coloured by how many bricks
+/// fall if each one is removed when `colour` is set.
+pub fn to_obj(input: &str, colour: bool) -> Result<String, ParseError> {
+    let stack = parse_input(input)?;
+    let toppled: Option<Vec<_>> = colour.then(|| {
+        let tree = DominatorTree::new(&stack);
+        (0..stack.bricks.len()).map(|i| tree.toppled(i)).collect()
+    });
+    Ok(stack.to_obj(toppled.as_deref()))
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let stack = parse_input(input)?;
+    let tree = DominatorTree::new(&stack);
+    Ok((0..stack.bricks.len()).map(|i| tree.toppled(i)).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            1,0,1~1,2,1
+            0,0,2~2,0,2
+            0,2,3~2,2,3
+            0,0,4~0,2,4
+            2,0,5~2,2,5
+            0,1,6~2,1,6
+            1,1,8~1,1,9";
+        assert_eq!(solve(input), Ok(7));
+
+        let report = topple_report(input).unwrap();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[0], "1,0,1~1,2,1 topples 6");
+        assert_eq!(lines[1], "0,0,2~2,0,2 topples 0, held up by 1,0,1~1,2,1");
+        assert_eq!(lines[5], "0,1,4~2,1,4 topples 1, held up by 1,0,1~1,2,1");
+        assert_eq!(lines[6], "1,1,5~1,1,6 topples 0, held up by 0,1,4~2,1,4");
+
+        let obj = to_obj(input, true).unwrap();
+        assert!(obj.contains("o brick0 1,0,1~1,2,1\nv 1 1 0 1.000 0.000 0.000\n"));
+        assert!(obj.contains("o brick5 0,1,4~2,1,4\nv 0 4 -1 1.000 0.833 0.000\n"));
+    }
+
+    #[test]
+    fn test_dominators() {
+        // Two columns joined by a beam, with a tower on the beam: the tower
+        // only depends on the beam, and nothing depends on either column.
+        let input = "
+            0,0,1~0,0,2
+            2,0,1~2,0,2
+            0,0,3~2,0,3
+            1,0,4~1,0,6
+            1,0,7~1,0,7";
+        let stack = parse_input(input).unwrap();
+        let tree = DominatorTree::new(&stack);
+        assert_eq!(tree.parent, vec![None, None, None, Some(2), Some(3)]);
+        assert_eq!(tree.sizes, vec![1, 1, 3, 2, 1]);
+        assert_eq!(solve(input), Ok(3));
+    }
+}
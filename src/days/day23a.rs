@@ -0,0 +1,174 @@
+use rustc_hash::FxHashMap;
+
+use crate::{
+    geometry::Direction,
+    grid::{Grid, Pos},
+    parse::ParseError,
+};
+
+/// The trail network with every corridor collapsed into a single edge
+/// between junctions, weighted by its length in steps.
+pub(crate) struct Trails {
+    /// `edges[a]` lists `(b, steps)` for every corridor leading from `a` to `b`.
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+fn slope(c: char) -> Option<Direction> {
+    match c {
+        '^' => Some(Direction::North),
+        '>' => Some(Direction::East),
+        'v' => Some(Direction::South),
+        '<' => Some(Direction::West),
+        _ => None,
+    }
+}
+
+impl Trails {
+    /// Collapses the map. With `slippery` set, a step off a slope must go
+    /// the way the slope points.
+    pub(crate) fn parse(input: &str, slippery: bool) -> Result<Trails, ParseError> {
+        let map = Grid::try_parse(input, |c| match c {
+            '#' | '.' | '^' | '>' | 'v' | '<' => Ok(c),
+            _ => Err(ParseError::invalid_char(c, "map tile")),
+        })?;
+        if map.height() == 0 {
+            return Err(ParseError::missing(input, "map"));
+        }
+        let open = |pos: Pos| map[pos] != '#';
+        let can_move = |from: Pos, dir: Direction| -> Option<Pos> {
+            if slippery && slope(map[from]).is_some_and(|s| s != dir) {
+                return None;
+            }
+            map.step(from, dir).filter(|&to| open(to))
+        };
+
+        let rows: Vec<&str> = input
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+        let find_gap = |y: usize| {
+            let x = map.row(y).iter().position(|&c| c == '.');
+            let x = x.ok_or_else(|| ParseError::new(rows[y], "no gap in the outer wall"))?;
+            Ok((x, y))
+        };
+        let start = find_gap(0)?;
+        let end = find_gap(map.height() - 1)?;
+        let mut junctions: Vec<Pos> = map
+            .positions()
+            .filter(|&pos| open(pos) && map.neighbors4(pos).filter(|&n| open(n)).count() >= 3)
+            .collect();
+        junctions.insert(0, start);
+        junctions.push(end);
+        let ids: FxHashMap<Pos, usize> =
+            junctions.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+
+        let mut edges = vec![vec![]; junctions.len()];
+        for (from, &junction) in junctions.iter().enumerate() {
+            for dir in Direction::ALL {
+                let Some(mut cur) = can_move(junction, dir) else {
+                    continue;
+                };
+                let mut prev = junction;
+                let mut steps = 1;
+                // Follow the corridor until it reaches another junction.
+                let to = loop {
+                    if let Some(&to) = ids.get(&cur) {
+                        break Some(to);
+                    }
+                    let next = Direction::ALL
+                        .into_iter()
+                        .filter_map(|dir| can_move(cur, dir))
+                        .find(|&next| next != prev);
+                    match next {
+                        Some(next) => (prev, cur) = (cur, next),
+                        None => break None,
+                    }
+                    steps += 1;
+                };
+                if let Some(to) = to {
+                    edges[from].push((to, steps));
+                }
+            }
+        }
+
+        Ok(Trails {
+            edges,
+            start: 0,
+            end: junctions.len() - 1,
+        })
+    }
+
+    /// The most steps a hike from start to end can take without visiting
+    /// any junction twice.
+    pub(crate) fn longest_hike(&self) -> Result<usize, ParseError> {
+        let mut visited = vec![false; self.edges.len()];
+        visited[self.start] = true;
+        self.longest_from(self.start, &mut visited)
+            .ok_or_else(|| ParseError::unsolvable("no hike reaches the end"))
+    }
+
+    fn longest_from(&self, node: usize, visited: &mut [bool]) -> Option<usize> {
+        if node == self.end {
+            return Some(0);
+        }
+        let mut longest = None;
+        for &(next, steps) in &self.edges[node] {
+            if visited[next] {
+                continue;
+            }
+            visited[next] = true;
+            if let Some(rest) = self.longest_from(next, visited) {
+                longest = longest.max(Some(steps + rest));
+            }
+            visited[next] = false;
+        }
+        longest
+    }
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let trails = Trails::parse(input, true)?;
+    trails.longest_hike()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            #.#####################
+            #.......#########...###
+            #######.#########.#.###
+            ###.....#.>.>.###.#.###
+            ###v#####.#v#.###.#.###
+            ###.>...#.#.#.....#...#
+            ###v###.#.#.#########.#
+            ###...#.#.#.......#...#
+            #####.#.#.#######.#.###
+            #.....#.#.#.......#...#
+            #.#####.#.#.#########v#
+            #.#...#...#...###...>.#
+            #.#.#v#######v###.###v#
+            #...#.>.#...>.>.#.###.#
+            #####v#.#.###v#.#.###.#
+            #.....#...#...#.#.#...#
+            #.#########.###.#.#.###
+            #...###...#...#...#.###
+            ###.###.#.###v#####v###
+            #...#...#.#.>.>.#.>.###
+            #.###.###.#.###.#.#v###
+            #.....###...###...#...#
+            #####################.#";
+        assert_eq!(solve(input), Ok(94));
+
+        assert_eq!(solve("").unwrap_err().to_string(), "missing map");
+        // The slope sends every hike back the way it came.
+        let err = solve("#.#\n#^#\n#.#").unwrap_err();
+        assert_eq!(err.to_string(), "no solution: no hike reaches the end");
+    }
+}
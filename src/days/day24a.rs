@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use num::rational::Ratio;
+
+use crate::{
+    geometry::Point3,
+    parse::{self, ParseError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Hailstone {
+    pub pos: Point3,
+    pub vel: Point3,
+}
+
+impl FromStr for Hailstone {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pos, vel) = parse::split_once(s, "@")?;
+        Ok(Hailstone {
+            pos: pos.parse()?,
+            vel: vel.parse()?,
+        })
+    }
+}
+
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    parse::lines(input, str::parse)
+}
+
+/// Where the paths of two hailstones cross in the XY plane, ignoring time,
+/// as long as both reach that point in the future. Exact, so that crossings
+/// right on the edge of the test area are judged correctly.
+fn crossing_xy(a: &Hailstone, b: &Hailstone) -> Option<(Ratio<i128>, Ratio<i128>)> {
+    let (ap, av) = (a.pos, a.vel);
+    let (bp, bv) = (b.pos, b.vel);
+    // Solve ap + t * av = bp + s * bv for t and s by Cramer's rule.
+    let det = (av.x * bv.y - av.y * bv.x) as i128;
+    if det == 0 {
+        return None;
+    }
+    let (dx, dy) = ((bp.x - ap.x) as i128, (bp.y - ap.y) as i128);
+    let t = Ratio::new(dx * bv.y as i128 - dy * bv.x as i128, det);
+    let s = Ratio::new(dx * av.y as i128 - dy * av.x as i128, det);
+    if t < Ratio::from(0) || s < Ratio::from(0) {
+        return None;
+    }
+    let x = Ratio::from(ap.x as i128) + t * av.x as i128;
+    let y = Ratio::from(ap.y as i128) + t * av.y as i128;
+    Some((x, y))
+}
+
+pub fn solve(input: &str, min: i64, max: i64) -> Result<usize, ParseError> {
+    let hailstones = parse_input(input)?;
+    let area = Ratio::from(min as i128)..=Ratio::from(max as i128);
+    let mut count = 0;
+    for (i, a) in hailstones.iter().enumerate() {
+        for b in &hailstones[i + 1..] {
+            if let Some((x, y)) = crossing_xy(a, b) {
+                if area.contains(&x) && area.contains(&y) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            19, 13, 30 @ -2,  1, -2
+            18, 19, 22 @ -1, -1, -2
+            20, 25, 34 @ -2, -2, -4
+            12, 31, 28 @ -1, -2, -1
+            20, 19, 15 @  1, -5, -3";
+        let hailstones = parse_input(input).unwrap();
+        let (x, y) = crossing_xy(&hailstones[0], &hailstones[1]).unwrap();
+        assert_eq!((x, y), (Ratio::new(43, 3), Ratio::new(46, 3)));
+        assert_eq!(crossing_xy(&hailstones[1], &hailstones[2]), None);
+        assert_eq!(crossing_xy(&hailstones[0], &hailstones[4]), None);
+        assert_eq!(solve(input, 7, 27), Ok(2));
+    }
+}
@@ -0,0 +1,167 @@
+use num::{BigInt, BigRational, Zero};
+
+use super::day24a::{parse_input, Hailstone};
+use crate::{geometry::Point3, parse::ParseError};
+
+/// Solves `matrix * x = rhs` by Gaussian elimination, or `None` if the
+/// system does not have a unique solution.
+fn solve_linear(
+    mut matrix: Vec<Vec<BigRational>>,
+    mut rhs: Vec<BigRational>,
+) -> Option<Vec<BigRational>> {
+    let n = rhs.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| !matrix[row][col].is_zero())?;
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+        for row in 0..n {
+            if row == col || matrix[row][col].is_zero() {
+                continue;
+            }
+            let factor = &matrix[row][col] / &matrix[col][col];
+            let pivot_row = matrix[col].clone();
+            for (cell, pivot) in matrix[row].iter_mut().zip(pivot_row).skip(col) {
+                *cell -= &factor * pivot;
+            }
+            let delta = &factor * &rhs[col];
+            rhs[row] -= delta;
+        }
+    }
+    Some((0..n).map(|i| &rhs[i] / &matrix[i][i]).collect())
+}
+
+fn big(n: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(n))
+}
+
+fn cross(a: Point3, b: Point3) -> Point3 {
+    Point3::new(
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    )
+}
+
+/// The rock at `P` with velocity `V` hits hailstone `i` iff `P - p_i` and
+/// `V - v_i` are parallel, i.e. `(P - p_i) × (V - v_i) = 0`. The nonlinear
+/// `P × V` term is the same for every hailstone, so subtracting the
+/// equations for `i` and `j` leaves three linear ones:
+///
+/// `P × (v_j - v_i) + (p_j - p_i) × V = p_j × v_j - p_i × v_i`
+fn pair_equations(i: &Hailstone, j: &Hailstone) -> [(Vec<BigRational>, BigRational); 3] {
+    let w = j.vel - i.vel;
+    let u = j.pos - i.pos;
+    let rhs = cross(j.pos, j.vel) - cross(i.pos, i.vel);
+    // Coefficients of (Px, Py, Pz, Vx, Vy, Vz) in each component.
+    let rows = [
+        ([0, w.z, -w.y, 0, -u.z, u.y], rhs.x),
+        ([-w.z, 0, w.x, u.z, 0, -u.x], rhs.y),
+        ([w.y, -w.x, 0, -u.y, u.x, 0], rhs.z),
+    ];
+    rows.map(|(coeffs, rhs)| (coeffs.map(big).to_vec(), big(rhs)))
+}
+
+/// Finds the rock's starting position and velocity from the first hailstone
+/// and any two others that give independent equations.
+fn throw_rock(hailstones: &[Hailstone]) -> Option<(Point3, Point3)> {
+    let first = hailstones.first()?;
+    for (j, a) in hailstones.iter().enumerate().skip(1) {
+        for b in &hailstones[j + 1..] {
+            let (matrix, rhs) = pair_equations(first, a)
+                .into_iter()
+                .chain(pair_equations(first, b))
+                .unzip();
+            let Some(solution) = solve_linear(matrix, rhs) else {
+                continue;
+            };
+            if !solution.iter().all(|v| v.is_integer()) {
+                continue;
+            }
+            let value: Option<Vec<i64>> = solution
+                .iter()
+                .map(|v| i64::try_from(v.to_integer()).ok())
+                .collect();
+            let Some(value) = value else {
+                continue;
+            };
+            let pos = Point3::new(value[0], value[1], value[2]);
+            let vel = Point3::new(value[3], value[4], value[5]);
+            return Some((pos, vel));
+        }
+    }
+    None
+}
+
+/// Whether a rock thrown from `pos` with `vel` meets `h` at a whole,
+/// non-negative time.
+fn hits(pos: Point3, vel: Point3, h: &Hailstone) -> bool {
+    let dp = pos - h.pos;
+    let dv = h.vel - vel;
+    let axis = [(dp.x, dv.x), (dp.y, dv.y), (dp.z, dv.z)]
+        .into_iter()
+        .find(|&(_, dv)| dv != 0);
+    let Some((dp_axis, dv_axis)) = axis else {
+        return dp == Point3::ORIGIN;
+    };
+    if dp_axis % dv_axis != 0 {
+        return false;
+    }
+    let t = dp_axis / dv_axis;
+    t >= 0 && pos + vel * t == h.pos + h.vel * t
+}
+
+/// The rock's starting position and velocity.
+fn find_rock(input: &str) -> Result<(Point3, Point3), ParseError> {
+    let hailstones = parse_input(input)?;
+    if hailstones.len() < 3 {
+        return Err(ParseError::unsolvable(
+            "fewer than three hailstones leave the rock's path open",
+        ));
+    }
+    throw_rock(&hailstones)
+        .filter(|&(pos, vel)| hailstones.iter().all(|h| hits(pos, vel, h)))
+        .ok_or_else(|| ParseError::unsolvable("no rock trajectory hits every hailstone"))
+}
+
+/// Where the rock is thrown from and how fast.
+pub fn explain(input: &str) -> Result<String, ParseError> {
+    let (pos, vel) = find_rock(input)?;
+    Ok(format!("Rock thrown from {} with velocity {}", pos, vel))
+}
+
+pub fn solve(input: &str) -> Result<i64, ParseError> {
+    let (pos, _) = find_rock(input)?;
+    Ok(pos.x + pos.y + pos.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ErrorKind;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            19, 13, 30 @ -2,  1, -2
+            18, 19, 22 @ -1, -1, -2
+            20, 25, 34 @ -2, -2, -4
+            12, 31, 28 @ -1, -2, -1
+            20, 19, 15 @  1, -5, -3";
+        let hailstones = parse_input(input).unwrap();
+        assert_eq!(
+            throw_rock(&hailstones),
+            Some((Point3::new(24, 13, 10), Point3::new(-3, 1, 2)))
+        );
+        assert_eq!(
+            explain(input).unwrap(),
+            "Rock thrown from (24, 13, 10) with velocity (-3, 1, 2)"
+        );
+        assert_eq!(solve(input), Ok(47));
+
+        let two = input.trim().lines().take(2).collect::<Vec<_>>().join("\n");
+        for input in ["", two.as_str()] {
+            let err = solve(input).unwrap_err();
+            assert_eq!(err.kind, ErrorKind::Unsolvable);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use rustc_hash::FxHashMap;
+
+use crate::parse::{self, ParseError};
+
+/// Two groups of components and the wires running between them.
+struct Cut {
+    /// Whether each component is on the same side as component 0.
+    side: Vec<bool>,
+    wires: Vec<(usize, usize)>,
+}
+
+/// The wiring diagram as an undirected graph over interned component ids.
+struct Wiring {
+    names: Vec<String>,
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl Wiring {
+    fn parse(input: &str) -> Result<Wiring, ParseError> {
+        let mut ids: FxHashMap<String, usize> = FxHashMap::default();
+        let mut wiring = Wiring {
+            names: vec![],
+            adjacent: vec![],
+        };
+        let mut intern = |name: &str, wiring: &mut Wiring| {
+            *ids.entry(name.to_string()).or_insert_with(|| {
+                wiring.names.push(name.to_string());
+                wiring.adjacent.push(vec![]);
+                wiring.names.len() - 1
+            })
+        };
+        for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (from, to) = parse::split_once(line, ": ")?;
+            let from = intern(from, &mut wiring);
+            for to in to.split_whitespace() {
+                let to = intern(to, &mut wiring);
+                wiring.adjacent[from].push(to);
+                wiring.adjacent[to].push(from);
+            }
+        }
+        Ok(wiring)
+    }
+
+    /// Finds a shortest path from `source` to `sink` along wires that still
+    /// have spare capacity, returning the predecessor of each node reached.
+    fn augmenting_path(
+        &self,
+        source: usize,
+        sink: usize,
+        flow: &FxHashMap<(usize, usize), i32>,
+    ) -> Vec<Option<usize>> {
+        let mut prev = vec![None; self.names.len()];
+        prev[source] = Some(source);
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            if node == sink {
+                break;
+            }
+            for &next in &self.adjacent[node] {
+                let used = flow.get(&(node, next)).copied().unwrap_or(0);
+                if prev[next].is_none() && used < 1 {
+                    prev[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        prev
+    }
+
+    /// Pushes unit flows from `source` to `sink` until no more fit or more
+    /// than `limit` have been found. Returns the number of flows and the
+    /// nodes still reachable from `source` afterwards.
+    fn max_flow(&self, source: usize, sink: usize, limit: usize) -> (usize, Vec<bool>) {
+        let mut flow: FxHashMap<(usize, usize), i32> = FxHashMap::default();
+        let mut paths = 0;
+        loop {
+            let prev = self.augmenting_path(source, sink, &flow);
+            if prev[sink].is_none() || paths > limit {
+                let reachable = prev.iter().map(|p| p.is_some()).collect();
+                return (paths, reachable);
+            }
+            let mut node = sink;
+            while node != source {
+                let from = prev[node].unwrap();
+                *flow.entry((from, node)).or_default() += 1;
+                *flow.entry((node, from)).or_default() -= 1;
+                node = from;
+            }
+            paths += 1;
+        }
+    }
+
+    /// Splits the components into two groups joined by exactly `cut` wires.
+    /// By max-flow/min-cut, any node on the far side from node 0 has
+    /// exactly `cut` edge-disjoint paths to it, and the residual graph of
+    /// that flow marks out node 0's side.
+    fn split(&self, cut: usize) -> Option<Cut> {
+        (1..self.names.len()).find_map(|sink| {
+            let (flows, side) = self.max_flow(0, sink, cut);
+            if flows != cut {
+                return None;
+            }
+            let wires = (0..self.names.len())
+                .filter(|&a| side[a])
+                .flat_map(|a| self.adjacent[a].iter().map(move |&b| (a, b)))
+                .filter(|&(_, b)| !side[b])
+                .collect();
+            Some(Cut { side, wires })
+        })
+    }
+}
+
+fn find_cut(input: &str) -> Result<(Wiring, Cut), ParseError> {
+    let wiring = Wiring::parse(input)?;
+    let cut = wiring.split(3);
+    let cut = cut.ok_or_else(|| ParseError::unsolvable("no three wires split the machine"))?;
+    Ok((wiring, cut))
+}
+
+/// The wires to disconnect, as `a/b` pairs of component names.
+pub fn explain(input: &str) -> Result<String, ParseError> {
+    let (wiring, cut) = find_cut(input)?;
+    let wires: Vec<_> = cut
+        .wires
+        .iter()
+        .map(|&(a, b)| format!("{}/{}", wiring.names[a], wiring.names[b]))
+        .collect();
+    Ok(format!("Disconnect {}", wires.join(", ")))
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let (_, cut) = find_cut(input)?;
+    let left = cut.side.iter().filter(|&&s| s).count();
+    Ok(left * (cut.side.len() - left))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "
+            jqt: rhn xhk nvd
+            rsh: frs pzl lsr
+            xhk: hfx
+            cmg: qnr nvd lhk bvb
+            rhn: xhk bvb hfx
+            bvb: xhk hfx
+            pzl: lsr hfx nvd
+            qnr: nvd
+            ntq: jqt hfx bvb xhk
+            nvd: lhk
+            lsr: lhk
+            rzs: qnr cmg lsr rsh
+            frs: qnr lhk lsr";
+        let wiring = Wiring::parse(input).unwrap();
+        let cut = wiring.split(3).unwrap();
+        let mut wires: Vec<_> = cut
+            .wires
+            .iter()
+            .map(|&(a, b)| {
+                let mut pair = [wiring.names[a].as_str(), wiring.names[b].as_str()];
+                pair.sort();
+                pair
+            })
+            .collect();
+        wires.sort();
+        assert_eq!(wires, vec![["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+        assert_eq!(solve(input), Ok(54));
+    }
+}
@@ -0,0 +1,49 @@
+pub mod day01a;
+pub mod day01b;
+pub mod day02a;
+pub mod day02b;
+pub mod day03a;
+pub mod day03b;
+pub mod day04a;
+pub mod day04b;
+pub mod day05a;
+pub mod day05b;
+pub mod day06a;
+pub mod day06b;
+pub mod day07a;
+pub mod day07b;
+pub mod day08a;
+pub mod day08b;
+pub mod day09a;
+pub mod day09b;
+pub mod day10a;
+pub mod day10b;
+pub mod day11a;
+pub mod day11b;
+pub mod day12a;
+pub mod day12b;
+pub mod day13a;
+pub mod day13b;
+pub mod day14a;
+pub mod day14b;
+pub mod day15a;
+pub mod day15b;
+pub mod day16a;
+pub mod day16b;
+pub mod day17a;
+pub mod day17b;
+pub mod day18a;
+pub mod day18b;
+pub mod day19a;
+pub mod day19b;
+pub mod day20a;
+pub mod day20b;
+pub mod day21a;
+pub mod day21b;
+pub mod day22a;
+pub mod day22b;
+pub mod day23a;
+pub mod day23b;
+pub mod day24a;
+pub mod day24b;
+pub mod day25a;
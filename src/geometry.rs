@@ -0,0 +1,254 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::parse::{self, ParseError};
+
+/// A compass direction in screen coordinates, so `North` decreases `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All four directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Parses `N`/`E`/`S`/`W` as well as `U`/`R`/`D`/`L`.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'N' | 'U' => Some(Direction::North),
+            'E' | 'R' => Some(Direction::East),
+            'S' | 'D' => Some(Direction::South),
+            'W' | 'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::from_char(c),
+            _ => None,
+        }
+        .ok_or_else(|| ParseError::new(s, "invalid direction"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(&self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn step(&self, dir: Direction) -> Point2 {
+        *self + Point2::from(dir)
+    }
+
+    pub fn neighbors4(&self) -> impl Iterator<Item = Point2> + '_ {
+        Direction::ALL.iter().map(move |&dir| self.step(dir))
+    }
+}
+
+impl From<Direction> for Point2 {
+    fn from(dir: Direction) -> Self {
+        let (dx, dy) = dir.offset();
+        Point2::new(dx as i64, dy as i64)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+}
+
+/// Parses comma-separated coordinates such as `1,0,1` or `19, 13, 30`.
+impl FromStr for Point3 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coords = s.split(',').map(str::trim);
+        let x = parse::number(parse::next(&mut coords, s, "x coordinate")?)?;
+        let y = parse::number(parse::next(&mut coords, s, "y coordinate")?)?;
+        let z = parse::number(parse::next(&mut coords, s, "z coordinate")?)?;
+        if let Some(extra) = coords.next() {
+            return Err(ParseError::new(extra, "unexpected coordinate"));
+        }
+        Ok(Point3 { x, y, z })
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_vector_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, scale: i64) -> $point {
+                $point { $($field: self.$field * scale),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Point2 { x, y });
+impl_vector_ops!(Point3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+        }
+        assert_eq!("U".parse(), Ok(Direction::North));
+        assert_eq!("L".parse(), Ok(Direction::West));
+        assert_eq!("S".parse(), Ok(Direction::South));
+        assert!("UD".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn test_points() {
+        let p = Point2::new(1, 2);
+        assert_eq!(p.step(Direction::North), Point2::new(1, 1));
+        assert_eq!(p + Point2::new(2, -5) * 2, Point2::new(5, -8));
+        assert_eq!(p.manhattan(Point2::new(-2, 6)), 7);
+        assert_eq!(p.neighbors4().count(), 4);
+
+        let q: Point3 = "19, 13, -30".parse().unwrap();
+        assert_eq!(q, Point3::new(19, 13, -30));
+        assert_eq!(q - q, Point3::ORIGIN);
+        assert_eq!(q.manhattan(Point3::ORIGIN), 62);
+        assert_eq!(
+            "1,2".parse::<Point3>().unwrap_err().message,
+            "missing z coordinate"
+        );
+        assert!("1,2,3,4".parse::<Point3>().is_err());
+    }
+}
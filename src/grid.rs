@@ -0,0 +1,315 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, Point2},
+    parse::ParseError,
+};
+
+/// An `(x, y)` cell position, with `y` counting rows down from the top.
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A dense row-major 2D grid.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid size mismatch");
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows have different lengths"
+        );
+        Grid::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Parses one row per non-empty line, mapping each character to a cell.
+    /// Surrounding whitespace on the input and on each line is ignored.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let rows = s
+            .trim()
+            .lines()
+            .map(|line| line.trim().chars().map(&mut f).collect())
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    /// Like `parse`, but for cell types that can reject a character. Also
+    /// reports ragged rows instead of panicking.
+    pub fn try_parse(
+        s: &str,
+        mut f: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for line in s.trim().lines().map(str::trim) {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| f(c).map_err(|err| err.at(&line[i..i + c.len_utf8()])))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let message = format!("expected a row of {} cells, found", first.len());
+                    return Err(ParseError::new(line, message));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `pos` by `(dx, dy)`, returning `None` if that leaves the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Moves `pos` one cell in `dir`, returning `None` if that leaves the grid.
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        self.offset(pos, dir.offset())
+    }
+
+    /// Converts a signed point to a cell position if it lies inside the grid.
+    pub fn checked_pos(&self, point: Point2) -> Option<Pos> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// The up to four orthogonal neighbours of `pos`, clockwise from north.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    pub fn map_with_pos<U>(&self, mut f: impl FnMut(Pos, &T) -> U) -> Grid<U> {
+        let cells = self.iter().map(|(pos, cell)| f(pos, cell)).collect();
+        Grid::new(self.width, self.height, cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn insert_row(&mut self, y: usize, row: Vec<T>) {
+        assert_eq!(row.len(), self.width, "Row length mismatch");
+        let at = y * self.width;
+        self.cells.splice(at..at, row);
+        self.height += 1;
+    }
+
+    pub fn insert_column(&mut self, x: usize, column: Vec<T>) {
+        assert_eq!(column.len(), self.height, "Column length mismatch");
+        let rows = self.rows().zip(column).map(|(row, value)| {
+            let mut row = row.to_vec();
+            row.insert(x, value);
+            row
+        });
+        *self = Grid::from_rows(rows.collect());
+    }
+
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flat_map(|col| col.cloned()).collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = self
+            .columns()
+            .flat_map(|col| col.cloned().collect::<Vec<_>>().into_iter().rev())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).expect("Grid position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).expect("Grid position out of bounds")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse(
+            "
+            abc
+            def",
+            |c| c,
+        );
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.step((1, 1), Direction::North), Some((1, 0)));
+        assert_eq!(grid.step((1, 1), Direction::South), None);
+        assert_eq!(grid.checked_pos(Point2::new(2, 1)), Some((2, 1)));
+        assert_eq!(grid.checked_pos(Point2::new(-1, 1)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+
+        let mut grid = grid;
+        grid.insert_row(1, vec!['x', 'y', 'z']);
+        grid.insert_column(0, vec!['1', '2', '3']);
+        assert_eq!(grid.to_string(), "1abc\n2xyz\n3def\n");
+
+        let digits = |c: char| c.to_digit(10).ok_or(ParseError::invalid_char(c, "digit"));
+        let input = "12\n3x";
+        let err = Grid::try_parse(input, digits).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+        assert_eq!(err.text, "x");
+        assert!(Grid::try_parse("12\n3", digits).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+    process,
+};
+
+/// Environment variable naming a directory that holds `dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Environment variable that turns on extra diagnostic output in the day
+/// binaries that have any. Some days also read a format from its value.
+pub const TRACE_VAR: &str = "AOC_TRACE";
+
+const DEFAULT_INPUT_DIR: &str = "inputs";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Picks where a day's input comes from. An explicit argument wins (`-`
+    /// meaning stdin), then `AOC_INPUT_DIR`, then `inputs/dayNN.txt`.
+    pub fn resolve(day: u8, arg: Option<&str>) -> Source {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None => {
+                let dir = env::var_os(INPUT_DIR_VAR)
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+                Source::Path(dir.join(format!("day{:02}.txt", day)))
+            }
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Path(path) => fs::read_to_string(path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err))),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub fn read(day: u8, arg: Option<&str>) -> io::Result<String> {
+    Source::resolve(day, arg).read()
+}
+
+/// Loads the input for a single-day binary, taking an optional path (or `-`)
+/// as the first command line argument. Exits with a message if it can't be
+/// read.
+pub fn load(day: u8) -> String {
+    let arg = env::args().nth(1);
+    read(day, arg.as_deref()).unwrap_or_else(|err| {
+        eprintln!("failed to read input for day {}: {}", day, err);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(Source::resolve(3, Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::resolve(3, Some("example.txt")),
+            Source::Path(PathBuf::from("example.txt"))
+        );
+        match Source::resolve(3, None) {
+            Source::Path(path) => assert!(path.ends_with("day03.txt")),
+            Source::Stdin => panic!("expected a path"),
+        }
+    }
+}
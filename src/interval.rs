@@ -0,0 +1,328 @@
+/// An axis-aligned box of integer points in `N` dimensions. Each axis covers
+/// the half-open range `lo[axis]..hi[axis]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HyperRect<const N: usize> {
+    pub lo: [u64; N],
+    pub hi: [u64; N],
+}
+
+impl<const N: usize> HyperRect<N> {
+    pub fn new(lo: [u64; N], hi: [u64; N]) -> Self {
+        HyperRect { lo, hi }
+    }
+
+    /// The box spanning `lo..hi` on every axis.
+    pub fn cube(lo: u64, hi: u64) -> Self {
+        HyperRect::new([lo; N], [hi; N])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|axis| self.lo[axis] >= self.hi[axis])
+    }
+
+    /// The number of integer points inside the box.
+    pub fn volume(&self) -> u64 {
+        (0..N)
+            .map(|axis| self.hi[axis].saturating_sub(self.lo[axis]))
+            .product()
+    }
+
+    pub fn contains(&self, point: &[u64; N]) -> bool {
+        (0..N).all(|axis| (self.lo[axis]..self.hi[axis]).contains(&point[axis]))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut rect = *self;
+        for axis in 0..N {
+            rect.lo[axis] = rect.lo[axis].max(other.lo[axis]);
+            rect.hi[axis] = rect.hi[axis].min(other.hi[axis]);
+        }
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// Cuts the box in two along `axis`: the part below `at` and the part at
+    /// or above it. Either side is `None` if nothing falls on it.
+    pub fn split(&self, axis: usize, at: u64) -> (Option<Self>, Option<Self>) {
+        let (mut below, mut above) = (*self, *self);
+        below.hi[axis] = below.hi[axis].min(at);
+        above.lo[axis] = above.lo[axis].max(at);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+}
+
+/// A run of `len` consecutive integers starting at `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Range {
+    pub start: u64,
+    pub len: u64,
+}
+
+impl Range {
+    pub fn new(start: u64, len: u64) -> Self {
+        Range { start, len }
+    }
+
+    /// One past the last integer in the range.
+    pub fn end(&self) -> u64 {
+        self.start + self.len
+    }
+
+    pub fn intersection(&self, other: &Range) -> Option<Range> {
+        let start = self.start.max(other.start);
+        let end = self.end().min(other.end());
+        (start < end).then(|| Range::new(start, end - start))
+    }
+}
+
+/// Sorts the ranges and joins any that overlap or touch, dropping empty ones.
+pub fn merge_ranges(ranges: &[Range]) -> Vec<Range> {
+    let mut ranges: Vec<_> = ranges.iter().filter(|r| r.len > 0).copied().collect();
+    ranges.sort();
+    let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end() => {
+                last.len = last.end().max(range.end()) - last.start;
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// A range of inputs that an `IntervalMap` shifts so `src.start` lands on
+/// `dst`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Piece {
+    src: Range,
+    dst: u64,
+}
+
+impl Piece {
+    fn map(&self, x: u64) -> u64 {
+        self.dst + (x - self.src.start)
+    }
+
+    fn dst_range(&self) -> Range {
+        Range::new(self.dst, self.src.len)
+    }
+}
+
+/// A function on `0..u64::MAX` that shifts each of a set of disjoint ranges
+/// by its own offset and leaves every other value where it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap {
+    /// Sorted pieces covering the whole domain, with neighbours that shift
+    /// by the same amount joined together.
+    pieces: Vec<Piece>,
+}
+
+impl IntervalMap {
+    pub fn identity() -> Self {
+        IntervalMap {
+            pieces: vec![Piece {
+                src: Range::new(0, u64::MAX),
+                dst: 0,
+            }],
+        }
+    }
+
+    /// Builds the map sending each `(src, dst)` range onto the range of the
+    /// same length starting at `dst`. `None` if two source ranges overlap or
+    /// a range would be shifted out of the domain.
+    pub fn new(mappings: impl IntoIterator<Item = (Range, u64)>) -> Option<Self> {
+        let mut mappings: Vec<_> = mappings
+            .into_iter()
+            .filter(|(src, _)| src.len > 0)
+            .collect();
+        mappings.sort();
+        let mut pieces = Vec::with_capacity(2 * mappings.len() + 1);
+        let mut next = 0;
+        for (src, dst) in mappings {
+            if src.start < next {
+                return None;
+            }
+            src.start.checked_add(src.len)?;
+            dst.checked_add(src.len)?;
+            if next < src.start {
+                pieces.push(Piece {
+                    src: Range::new(next, src.start - next),
+                    dst: next,
+                });
+            }
+            pieces.push(Piece { src, dst });
+            next = src.end();
+        }
+        pieces.push(Piece {
+            src: Range::new(next, u64::MAX - next),
+            dst: next,
+        });
+        Some(IntervalMap::from_pieces(pieces))
+    }
+
+    /// Wraps pieces that already tile the domain in order, joining neighbours
+    /// that shift by the same amount.
+    fn from_pieces(pieces: Vec<Piece>) -> Self {
+        let mut joined: Vec<Piece> = Vec::with_capacity(pieces.len());
+        for piece in pieces.into_iter().filter(|p| p.src.len > 0) {
+            match joined.last_mut() {
+                Some(last) if last.dst_range().end() == piece.dst => last.src.len += piece.src.len,
+                _ => joined.push(piece),
+            }
+        }
+        IntervalMap { pieces: joined }
+    }
+
+    /// Index of the piece holding `x`.
+    fn find(&self, x: u64) -> usize {
+        self.pieces.partition_point(|p| p.src.end() <= x)
+    }
+
+    pub fn get(&self, x: u64) -> u64 {
+        self.pieces.get(self.find(x)).map_or(x, |p| p.map(x))
+    }
+
+    /// Every value the map sends something in `ranges` to, merged.
+    pub fn image(&self, ranges: &[Range]) -> Vec<Range> {
+        let mut image = Vec::new();
+        for range in ranges.iter().filter(|r| r.len > 0) {
+            for piece in &self.pieces[self.find(range.start)..] {
+                if piece.src.start >= range.end() {
+                    break;
+                }
+                if let Some(part) = piece.src.intersection(range) {
+                    image.push(Range::new(piece.map(part.start), part.len));
+                }
+            }
+        }
+        merge_ranges(&image)
+    }
+
+    /// Every value the map sends into `ranges`, merged.
+    pub fn preimage(&self, ranges: &[Range]) -> Vec<Range> {
+        let ranges = merge_ranges(ranges);
+        let mut preimage = Vec::new();
+        for piece in &self.pieces {
+            for range in &ranges {
+                if let Some(part) = piece.dst_range().intersection(range) {
+                    let start = piece.src.start + (part.start - piece.dst);
+                    preimage.push(Range::new(start, part.len));
+                }
+            }
+        }
+        merge_ranges(&preimage)
+    }
+
+    /// The map that applies `self` and then `then`.
+    pub fn compose(&self, then: &IntervalMap) -> IntervalMap {
+        let mut pieces = Vec::new();
+        for piece in &self.pieces {
+            let dst = piece.dst_range();
+            for next in &then.pieces[then.find(dst.start)..] {
+                if next.src.start >= dst.end() {
+                    break;
+                }
+                if let Some(part) = next.src.intersection(&dst) {
+                    pieces.push(Piece {
+                        src: Range::new(piece.src.start + (part.start - piece.dst), part.len),
+                        dst: next.map(part.start),
+                    });
+                }
+            }
+        }
+        IntervalMap::from_pieces(pieces)
+    }
+
+    /// The map undoing this one, if it is a bijection.
+    pub fn invert(&self) -> Option<IntervalMap> {
+        let mut pieces: Vec<_> = self
+            .pieces
+            .iter()
+            .map(|p| Piece {
+                src: p.dst_range(),
+                dst: p.src.start,
+            })
+            .collect();
+        pieces.sort_by_key(|p| p.src.start);
+        let mut next = 0;
+        for piece in &pieces {
+            if piece.src.start != next {
+                return None;
+            }
+            next = piece.src.end();
+        }
+        (next == u64::MAX).then(|| IntervalMap::from_pieces(pieces))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyper_rect() {
+        let rect = HyperRect::<3>::cube(1, 5);
+        assert_eq!(rect.volume(), 64);
+        assert!(rect.contains(&[1, 4, 2]));
+        assert!(!rect.contains(&[1, 5, 2]));
+
+        let (below, above) = rect.split(1, 3);
+        assert_eq!(below.unwrap().volume() + above.unwrap().volume(), 64);
+        assert_eq!(above.unwrap().lo, [1, 3, 1]);
+        assert_eq!(rect.split(0, 1), (None, Some(rect)));
+        assert_eq!(rect.split(0, 9), (Some(rect), None));
+
+        let other = HyperRect::new([0, 2, 4], [2, 9, 9]);
+        let both = rect.intersection(&other).unwrap();
+        assert_eq!(both, HyperRect::new([1, 2, 4], [2, 5, 5]));
+        assert_eq!(both.volume(), 3);
+        assert_eq!(rect.intersection(&HyperRect::cube(5, 6)), None);
+    }
+
+    #[test]
+    fn test_interval_map() {
+        let ranges = [Range::new(5, 3), Range::new(0, 2), Range::new(8, 1)];
+        assert_eq!(
+            merge_ranges(&ranges),
+            vec![Range::new(0, 2), Range::new(5, 4)]
+        );
+
+        // The seed-to-soil and soil-to-fertilizer maps from the day 5 example.
+        let soil = IntervalMap::new([(Range::new(98, 2), 50), (Range::new(50, 48), 52)]).unwrap();
+        let fertilizer = IntervalMap::new([
+            (Range::new(15, 37), 0),
+            (Range::new(52, 2), 37),
+            (Range::new(0, 15), 39),
+        ])
+        .unwrap();
+        assert_eq!(soil.get(79), 81);
+        assert_eq!(soil.get(99), 51);
+        assert_eq!(soil.get(10), 10);
+        assert_eq!(
+            soil.image(&[Range::new(96, 6)]),
+            vec![Range::new(50, 2), Range::new(98, 4)]
+        );
+        assert_eq!(
+            soil.preimage(&[Range::new(50, 3)]),
+            vec![Range::new(50, 1), Range::new(98, 2)]
+        );
+
+        let both = soil.compose(&fertilizer);
+        for seed in [0, 14, 50, 79, 98, 99, 100] {
+            assert_eq!(both.get(seed), fertilizer.get(soil.get(seed)));
+        }
+        let inverse = both.invert().unwrap();
+        assert!((0..200).all(|seed| inverse.get(both.get(seed)) == seed));
+        assert_eq!(both.compose(&inverse), IntervalMap::identity());
+
+        assert_eq!(
+            IntervalMap::new([(Range::new(0, 5), 10), (Range::new(4, 2), 20)]),
+            None
+        );
+        let squash = IntervalMap::new([(Range::new(0, 5), 10)]).unwrap();
+        assert_eq!(squash.invert(), None);
+    }
+}
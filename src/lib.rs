@@ -0,0 +1,10 @@
+pub mod cycle;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod parse;
+pub mod polygon;
+pub mod registry;
+pub mod search;
@@ -0,0 +1,203 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    process,
+    str::FromStr,
+};
+
+/// Why a day could not be answered: malformed puzzle input, with what was
+/// wrong, the text it was wrong about and where that text sits in the input,
+/// or well-formed input that has no answer.
+///
+/// Parsers only see the piece of input they are handed, so errors are raised
+/// against a slice of it and `locate` later turns that slice back into a
+/// line and column once the whole input is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    pub day: Option<u8>,
+    /// One-based line and column of `text`, when known.
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: String,
+    pub message: String,
+    /// Address of `text` in the string it was sliced from. Only compared
+    /// against the bounds of the input passed to `locate`, never read through.
+    addr: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input could not be read.
+    Malformed,
+    /// The input was read, but the puzzle it describes has no solution. These
+    /// errors are about the input as a whole, so they have no location.
+    Unsolvable,
+}
+
+impl ParseError {
+    /// An error about `text`, which should be a slice of the puzzle input
+    /// (an empty slice marks a position, e.g. where a field is missing).
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            kind: ErrorKind::Malformed,
+            day: None,
+            line: None,
+            column: None,
+            text: text.to_string(),
+            message: message.into(),
+            addr: text.as_ptr() as usize,
+        }
+    }
+
+    /// An error for input that was read but has no answer, saying what could
+    /// not be found.
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        let mut err = ParseError::new("", message);
+        err.kind = ErrorKind::Unsolvable;
+        err
+    }
+
+    /// An error for a field that should have followed the end of `s`.
+    pub fn missing(s: &str, what: &str) -> Self {
+        ParseError::new(&s[s.len()..], format!("missing {}", what))
+    }
+
+    /// An error about a single character, for parsers that see characters
+    /// one at a time. Their caller should point it at the input with `at`.
+    pub fn invalid_char(c: char, what: &str) -> Self {
+        let mut err = ParseError::new("", format!("invalid {}", what));
+        err.text = c.to_string();
+        err
+    }
+
+    /// Re-points the error at `text`, a slice of the input.
+    pub fn at(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self.addr = text.as_ptr() as usize;
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Works out the line and column of the offending text, if it was sliced
+    /// from `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.kind == ErrorKind::Unsolvable
+            || self.line.is_some()
+            || self.addr < start
+            || self.addr > start + input.len()
+        {
+            return self;
+        }
+        let before = &input[..self.addr - start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.line = Some(before.matches('\n').count() + 1);
+        self.column = Some(before[line_start..].chars().count() + 1);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, "line {}, column {}: ", line, column)?;
+        }
+        if self.kind == ErrorKind::Unsolvable {
+            write!(f, "no solution: ")?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a number, reporting `s` as the offending text if it is not one.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "invalid number"))
+}
+
+/// Splits `s` around the first `sep`, which is required to be there.
+pub fn split_once<'a>(s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::new(s, format!("expected '{}' in", sep)))
+}
+
+/// Takes the next field from `parts`, a split of `s`.
+pub fn next<'a>(
+    parts: &mut impl Iterator<Item = &'a str>,
+    s: &str,
+    what: &str,
+) -> Result<&'a str, ParseError> {
+    parts.next().ok_or_else(|| ParseError::missing(s, what))
+}
+
+/// Parses every non-blank line of `input` with `f`, ignoring surrounding
+/// whitespace on each line.
+pub fn lines<'a, T>(
+    input: &'a str,
+    f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(f)
+        .collect()
+}
+
+/// Unwraps a day's answer for the per-day binaries, reporting a parse error
+/// against `input` and exiting instead of panicking.
+pub fn or_exit<T>(day: u8, input: &str, result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", err.locate(input).in_day(day));
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "1 2\n3 x 5\n";
+        let err = lines(input, |line| {
+            line.split(' ')
+                .map(number::<u32>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        let err = err.locate(input).in_day(9);
+        assert_eq!((err.line, err.column), (Some(2), Some(3)));
+        assert_eq!(
+            err.to_string(),
+            "day 9, line 2, column 3: invalid number 'x'"
+        );
+
+        let line = &input[..3];
+        let mut parts = line.split(' ');
+        assert_eq!(next(&mut parts, line, "x"), Ok("1"));
+        next(&mut parts, line, "y").unwrap();
+        let err = next(&mut parts, line, "z").unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (Some(1), Some(4)));
+        assert_eq!(err.to_string(), "line 1, column 4: missing z");
+
+        let err = ParseError::new("owned", "not from the input").locate(input);
+        assert_eq!(err.line, None);
+        let err = ParseError::unsolvable("no route").locate(input).in_day(9);
+        assert_eq!((err.kind, err.line), (ErrorKind::Unsolvable, None));
+        assert_eq!(err.to_string(), "day 9, no solution: no route");
+        assert!(split_once("a-b", "->").is_err());
+    }
+}
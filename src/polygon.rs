@@ -0,0 +1,108 @@
+use crate::geometry::{Direction, Point2};
+
+/// A closed polygon on the integer lattice, stored as its corner points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// Traces a polygon by walking `distance` cells in each direction in turn,
+    /// starting from the origin, or `None` if the walk does not end where it
+    /// began.
+    pub fn from_steps(steps: impl IntoIterator<Item = (Direction, i64)>) -> Option<Self> {
+        let mut cur = Point2::ORIGIN;
+        let mut vertices = vec![cur];
+        for (dir, distance) in steps {
+            cur += Point2::from(dir) * distance;
+            vertices.push(cur);
+        }
+        if cur != Point2::ORIGIN {
+            return None;
+        }
+        if vertices.len() > 1 {
+            vertices.pop();
+        }
+        Some(Polygon { vertices })
+    }
+
+    pub fn vertices(&self) -> &[Point2] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the enclosed area, via the shoelace formula. Doubling keeps the
+    /// result exact for polygons whose area is a half-integer.
+    pub fn double_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i64>()
+            .abs()
+    }
+
+    /// The number of lattice points lying on the polygon's edges.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| num::integer::gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside the polygon, by Pick's
+    /// theorem: `A = I + B/2 - 1`. A point or a line has no inside.
+    pub fn interior_points(&self) -> i64 {
+        if self.vertices.len() < 3 {
+            return 0;
+        }
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// The number of lattice points inside or on the polygon. When each point
+    /// stands for a unit cell, this is the area the polygon's cells cover.
+    pub fn enclosed_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon() {
+        let square = Polygon::from_steps([
+            (Direction::East, 2),
+            (Direction::South, 2),
+            (Direction::West, 2),
+            (Direction::North, 2),
+        ])
+        .unwrap();
+        assert_eq!(square.vertices().len(), 4);
+        assert_eq!(square.double_area(), 8);
+        assert_eq!(square.boundary_points(), 8);
+        assert_eq!(square.interior_points(), 1);
+        assert_eq!(square.enclosed_points(), 9);
+
+        let triangle = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(3, 0),
+            Point2::new(0, 3),
+        ]);
+        assert_eq!(triangle.double_area(), 9);
+        assert_eq!(triangle.boundary_points(), 9);
+        assert_eq!(triangle.interior_points(), 1);
+
+        assert_eq!(Polygon::from_steps([(Direction::East, 2)]), None);
+        let line = Polygon::from_steps([(Direction::East, 2), (Direction::West, 2)]).unwrap();
+        assert_eq!(line.interior_points(), 0);
+        let point = Polygon::from_steps([]).unwrap();
+        assert_eq!(point.enclosed_points(), 0);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{days::*, parse::ParseError};
+
+/// A single day/part of the calendar, callable with the raw puzzle input.
+/// Parse errors come back located in that input and tagged with the day.
+pub struct Solution {
+    pub day: u8,
+    pub part: char,
+    pub solve: fn(&str) -> Result<String, ParseError>,
+}
+
+impl Solution {
+    pub fn name(&self) -> String {
+        format!("day{:02}{}", self.day, self.part)
+    }
+}
+
+macro_rules! solution {
+    ($day:expr, $part:expr, $module:ident $(, $arg:expr)*) => {
+        Solution {
+            day: $day,
+            part: $part,
+            solve: |input| {
+                $module::solve(input $(, $arg)*)
+                    .map(|ans| ans.to_string())
+                    .map_err(|err| err.locate(input).in_day($day))
+            },
+        }
+    };
+}
+
+/// Every solved day/part, ordered by day then part. Parts whose `solve` takes
+/// extra arguments are registered with the values the real puzzle uses.
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(1, 'a', day01a),
+    solution!(1, 'b', day01b),
+    solution!(2, 'a', day02a),
+    solution!(2, 'b', day02b),
+    solution!(3, 'a', day03a),
+    solution!(3, 'b', day03b),
+    solution!(4, 'a', day04a),
+    solution!(4, 'b', day04b),
+    solution!(5, 'a', day05a),
+    solution!(5, 'b', day05b),
+    solution!(6, 'a', day06a),
+    solution!(6, 'b', day06b),
+    solution!(7, 'a', day07a),
+    solution!(7, 'b', day07b),
+    solution!(8, 'a', day08a),
+    solution!(8, 'b', day08b),
+    solution!(9, 'a', day09a),
+    solution!(9, 'b', day09b),
+    solution!(10, 'a', day10a),
+    solution!(10, 'b', day10b),
+    solution!(11, 'a', day11a),
+    solution!(11, 'b', day11b, 1000000),
+    solution!(12, 'a', day12a),
+    solution!(12, 'b', day12b),
+    solution!(13, 'a', day13a),
+    solution!(13, 'b', day13b),
+    solution!(14, 'a', day14a),
+    solution!(14, 'b', day14b, 1000000000),
+    solution!(15, 'a', day15a),
+    solution!(15, 'b', day15b),
+    solution!(16, 'a', day16a),
+    solution!(16, 'b', day16b),
+    solution!(17, 'a', day17a),
+    solution!(17, 'b', day17b),
+    solution!(18, 'a', day18a),
+    solution!(18, 'b', day18b),
+    solution!(19, 'a', day19a),
+    solution!(19, 'b', day19b),
+    solution!(20, 'a', day20a),
+    solution!(20, 'b', day20b),
+    solution!(21, 'a', day21a, 64),
+    solution!(21, 'b', day21b, 26501365),
+    solution!(22, 'a', day22a),
+    solution!(22, 'b', day22b),
+    solution!(23, 'a', day23a),
+    solution!(23, 'b', day23b),
+    solution!(24, 'a', day24a, 200000000000000, 400000000000000),
+    solution!(24, 'b', day24b),
+    solution!(25, 'a', day25a),
+];
+
+pub fn find(day: u8, part: char) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.day == day && s.part == part)
+}
+
+pub fn for_day(day: u8) -> impl Iterator<Item = &'static Solution> {
+    SOLUTIONS.iter().filter(move |s| s.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        for pair in SOLUTIONS.windows(2) {
+            assert!((pair[0].day, pair[0].part) < (pair[1].day, pair[1].part));
+        }
+        assert_eq!(find(14, 'b').unwrap().name(), "day14b");
+        assert!(find(25, 'b').is_none());
+        assert_eq!(for_day(21).count(), 2);
+
+        let solution = find(9, 'a').unwrap();
+        let input = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45";
+        assert_eq!((solution.solve)(input), Ok("114".to_string()));
+        let err = (solution.solve)("0 3 six").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 9, line 1, column 5: invalid number 'six'"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+/// The cheapest route found by a search, from a start state to a goal state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    /// Every state along the route, including the start and the goal.
+    pub states: Vec<S>,
+}
+
+impl<S> Path<S> {
+    pub fn start(&self) -> &S {
+        self.states.first().expect("Path has no states")
+    }
+
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("Path has no states")
+    }
+}
+
+/// Dijkstra's algorithm over an implicit graph. `successors` yields each
+/// neighbouring state together with the cost of the edge leading to it.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search over an implicit graph. `heuristic` must never overestimate the
+/// remaining cost to a goal, otherwise the returned path may not be optimal.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    // States are interned so the heap and parent links only carry indices.
+    let mut states: Vec<S> = Vec::new();
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut costs: Vec<u64> = Vec::new();
+    let mut index: FxHashMap<S, usize> = FxHashMap::default();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if index.contains_key(&start) {
+            continue;
+        }
+        let id = states.len();
+        queue.push(Reverse((heuristic(&start), 0, id)));
+        index.insert(start.clone(), id);
+        states.push(start);
+        parents.push(None);
+        costs.push(0);
+    }
+
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        if cost > costs[id] {
+            continue;
+        }
+        if is_goal(&states[id]) {
+            let mut path = vec![id];
+            while let Some(parent) = parents[*path.last().unwrap()] {
+                path.push(parent);
+            }
+            let states = path.into_iter().rev().map(|id| states[id].clone());
+            return Some(Path {
+                cost,
+                states: states.collect(),
+            });
+        }
+        for (next, edge_cost) in successors(&states[id]) {
+            let next_cost = cost + edge_cost;
+            let next_id = match index.get(&next) {
+                Some(&next_id) if costs[next_id] <= next_cost => continue,
+                Some(&next_id) => next_id,
+                None => {
+                    let next_id = states.len();
+                    index.insert(next.clone(), next_id);
+                    states.push(next);
+                    parents.push(None);
+                    costs.push(u64::MAX);
+                    next_id
+                }
+            };
+            costs[next_id] = next_cost;
+            parents[next_id] = Some(id);
+            let estimate = next_cost + heuristic(&states[next_id]);
+            queue.push(Reverse((estimate, next_cost, next_id)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        // 0 -> 1 -> 3 costs 2 + 5, 0 -> 2 -> 3 costs 4 + 1.
+        let edges = |n: &u32| match n {
+            0 => vec![(1, 2), (2, 4)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let path = dijkstra([0], edges, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states, vec![0, 2, 3]);
+        assert_eq!((*path.start(), *path.goal()), (0, 3));
+        assert_eq!(dijkstra([0], edges, |&n| n == 4), None);
+
+        // Walking a number line towards 10, guided by the distance left.
+        let path = astar(
+            [0i64],
+            |&n| [(n - 1, 1), (n + 1, 1)],
+            |&n| (10 - n).unsigned_abs(),
+            |&n| n == 10,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states, (0..=10).collect::<Vec<_>>());
+    }
+}